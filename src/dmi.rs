@@ -1,4 +1,5 @@
 pub mod entrypoint;
 pub mod err;
+pub mod eventlog;
 pub mod raw;
 pub mod table;
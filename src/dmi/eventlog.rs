@@ -0,0 +1,271 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+// Parser for the System Event Log area described by a Type 15
+// structure. See section 7.16 of SMBIOS spec 3.5.0.
+
+use crate::dmi::err;
+use crate::dmi::table::decode_bcd;
+use crate::dmi::table::Table;
+use std::fmt;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::SeekFrom;
+use std::path::Path;
+
+const TYPE1_HEADER_LEN: usize = 16;
+const RECORD_HEADER_LEN: usize = 8;
+
+// Section 7.16.6.1 of SMBIOS spec 3.5.0
+pub fn event_type(b: u8) -> &'static str {
+    match b {
+        0x1 => "Single-bit ECC memory error",
+        0x2 => "Multi-bit ECC memory error",
+        0x3 => "Parity memory error",
+        0x4 => "Bus time-out",
+        0x5 => "I/O Channel Check",
+        0x6 => "Software NMI",
+        0x7 => "POST Memory Resize",
+        0x8 => "POST Error",
+        0x9 => "PCI Parity Error",
+        0xa => "PCI System Error",
+        0xb => "CPU Failure",
+        0xc => "EISA FailSafe Timer time-out",
+        0xd => "Correctable memory log disabled",
+        0xe => "Logging disabled for a specific Event Type",
+        0x10 => "System Limit Exceeded",
+        0x11 => "Asynchronous hardware timer expired and issued a system reset",
+        0x12 => "System configuration information",
+        0x13 => "Hard-disk information",
+        0x14 => "System reconfigured",
+        0x15 => "Uncorrectable CPU-complex error",
+        0x16 => "Log Area Reset/Cleared",
+        0x17 => "System boot",
+        0x80..=0xfe => "OEM-specific",
+        0xff => "End of log",
+        _ => "Unused",
+    }
+}
+
+// Timestamp fields are BCD; firmware that gets this wrong shouldn't
+// turn into a plausible looking date
+fn bcd_field(b: u8) -> String {
+    match decode_bcd(b) {
+        Some(v) => format!("{:02}", v),
+        None => String::from("Unknown"),
+    }
+}
+
+pub struct EventRecord {
+    event_type: u8,
+    unread: bool,
+    timestamp: [u8; 6],
+    data: Vec<u8>,
+}
+
+impl EventRecord {
+    // The two-digit BCD year covers 1980 through 2079
+    fn year(&self) -> String {
+        match decode_bcd(self.timestamp[0]).map(u16::from) {
+            Some(y) if y >= 80 => (1900 + y).to_string(),
+            Some(y) => (2000 + y).to_string(),
+            None => String::from("Unknown"),
+        }
+    }
+}
+
+impl fmt::Display for EventRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}-{} {}:{}:{} {}",
+            self.year(),
+            bcd_field(self.timestamp[1]),
+            bcd_field(self.timestamp[2]),
+            bcd_field(self.timestamp[3]),
+            bcd_field(self.timestamp[4]),
+            bcd_field(self.timestamp[5]),
+            event_type(self.event_type)
+        )?;
+        if self.unread {
+            write!(f, " (unread)")?;
+        }
+        if !self.data.is_empty() {
+            write!(f, ", data:")?;
+            for b in self.data.iter() {
+                write!(f, " {:02x}", b)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Parses event records until the end-of-log marker or the end of the
+// data, whichever comes first
+pub fn parse_records(data: &[u8]) -> Vec<EventRecord> {
+    let mut records: Vec<EventRecord> = Vec::new();
+    let mut pos = 0;
+    while pos + RECORD_HEADER_LEN <= data.len() {
+        if data[pos] == 0xff {
+            break;
+        }
+        // The high bit of the length is cleared once the record has
+        // been read
+        let len = usize::from(data[pos + 1] & 127);
+        if len < RECORD_HEADER_LEN || pos + len > data.len() {
+            warn!("Malformed event log record at offset 0x{:x}", pos);
+            break;
+        }
+        let mut timestamp: [u8; 6] = [0; 6];
+        timestamp.clone_from_slice(&data[pos + 2..pos + RECORD_HEADER_LEN]);
+        records.push(EventRecord {
+            event_type: data[pos],
+            unread: data[pos + 1] & 128 != 0,
+            timestamp,
+            data: data[pos + RECORD_HEADER_LEN..pos + len].to_vec(),
+        });
+        pos += len;
+    }
+    records
+}
+
+pub struct EventLog {
+    header: Option<Vec<u8>>,
+    records: Vec<EventRecord>,
+}
+
+impl EventLog {
+    // Parses a log area laid out as described by the given Type 15
+    // structure
+    pub fn parse(area: &[u8], table: &Table) -> EventLog {
+        let header_start = usize::from(table.log_header_offset());
+        let data_start = usize::from(table.log_data_offset()).min(area.len());
+        let header = match table.log_header_format() {
+            0x1 if header_start + TYPE1_HEADER_LEN <= area.len() => {
+                Some(area[header_start..header_start + TYPE1_HEADER_LEN].to_vec())
+            }
+            _ => None,
+        };
+        EventLog {
+            header,
+            records: parse_records(&area[data_start..]),
+        }
+    }
+
+    // Reads the log area from a memory image or file, starting at
+    // the given offset
+    pub fn read(path: &Path, offset: u64, table: &Table) -> Result<EventLog, err::DMIParserError> {
+        let mut f = File::open(path)?;
+        f.seek(SeekFrom::Start(offset))?;
+        let mut area = vec![0; usize::from(table.log_area_length())];
+        f.read_exact(&mut area)?;
+        Ok(EventLog::parse(&area, table))
+    }
+}
+
+impl fmt::Display for EventLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(h) = &self.header {
+            writeln!(f, "Log Header Revision: {}", h[0xf])?;
+            writeln!(f, "Multiple Event Time Window: {} minutes", h[0x5])?;
+            writeln!(f, "Multiple Event Count Increment: {}", h[0x6])?;
+        }
+        writeln!(f, "Event records: {}", self.records.len())?;
+        for (i, r) in self.records.iter().enumerate() {
+            writeln!(f, "Record {}: {}", i + 1, r)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dmi::table::table;
+    use crate::dmi::table::TableId;
+
+    #[test]
+    fn t_parse_records() {
+        let data = [
+            0x1, 0x8a, 0x21, 0x03, 0x14, 0x09, 0x30, 0x59, 0x40, 0x00, // unread ECC error
+            0x17, 0x08, 0x99, 0x12, 0x31, 0x23, 0x59, 0x00, // system boot
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        ];
+        let records = parse_records(&data);
+        assert_eq!(records.len(), 2);
+        let r = format!("{}", records[0]);
+        assert_eq!(
+            r,
+            "2021-03-14 09:30:59 Single-bit ECC memory error (unread), data: 40 00"
+        );
+        let r = format!("{}", records[1]);
+        assert_eq!(r, "1999-12-31 23:59:00 System boot");
+    }
+
+    #[test]
+    fn t_parse_type1_header() {
+        let sel = table(
+            TableId::SystemEventLog,
+            [
+                15,   // type
+                0x17, // length
+                0x0,  // handle (2 bytes)
+                0xf,
+                0x20, 0x0, // log area length
+                0x0, 0x0,  // header start offset
+                0x10, 0x0, // data start offset
+                0x3,  // memory-mapped
+                0x1,  // valid
+                0, 0, 0, 0, // change token
+                0, 0, 0, 0, // access method address
+                0x1,  // type 1 log header
+                0,    // descriptor count
+                0x2,  // descriptor length
+            ]
+            .to_vec(),
+            [].to_vec(),
+        );
+        let area = [
+            0, 0, 0, 0, 0, // OEM reserved
+            0x3, // multiple event time window
+            0x2, // multiple event count increment
+            0, 0, 0, 0, 0, // CMOS fields
+            0x7, 0x7, 0x7, // reserved
+            0x1, // header revision
+            0x17, 0x08, 0x24, 0x02, 0x29, 0x08, 0x15, 0x00, // system boot
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        ];
+        let r = format!("{}", EventLog::parse(&area, &sel));
+        println!("{}", r);
+        assert!(r.contains("Log Header Revision: 1\n"));
+        assert!(r.contains("Multiple Event Time Window: 3 minutes"));
+        assert!(r.contains("Record 1: 2024-02-29 08:15:00 System boot"));
+    }
+
+    #[test]
+    fn t_invalid_bcd_timestamp() {
+        let data = [0x17, 0x08, 0x2a, 0x13, 0x01, 0x0f, 0x30, 0x00];
+        let records = parse_records(&data);
+        let r = format!("{}", records[0]);
+        assert_eq!(r, "Unknown-13-01 Unknown:30:00 System boot");
+    }
+
+    #[test]
+    fn t_parse_truncated_record() {
+        let data = [0x1, 0x10, 0x21, 0x03, 0x14, 0x09, 0x30, 0x59, 0x40, 0x00];
+        assert!(parse_records(&data).is_empty());
+    }
+}
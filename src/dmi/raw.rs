@@ -17,6 +17,12 @@
 use crate::dmi::entrypoint;
 use crate::dmi::err;
 use crate::dmi::table::Table;
+use crate::dmi::table::END_OF_TABLE;
+use crate::dmi::table::INACTIVE;
+use crate::dmi::table::TABLES;
+use std::fs::File;
+use std::io::Read;
+use std::io::Seek;
 
 pub fn decode_entrypoint() -> Result<entrypoint::Entrypoint, err::DMIParserError> {
     let t = entrypoint::Entrypoint::read()?;
@@ -26,11 +32,60 @@ pub fn decode_entrypoint() -> Result<entrypoint::Entrypoint, err::DMIParserError
 pub fn read_raw_table(
     id: u8,
     entrypoint: entrypoint::Entrypoint,
-) -> Result<Table, err::DMIParserError> {
-    let table_size = entrypoint.table_size();
-    let mut t = Table::read()?;
+    show_inactive: bool,
+) -> Result<Vec<Table>, err::DMIParserError> {
+    let tables = read_all_tables(&entrypoint, show_inactive)?;
+    if id == INACTIVE && !show_inactive {
+        info!("Inactive structures are hidden; use --show-inactive to print them");
+    }
+    for t in tables.iter().filter(|t| t.id() == id) {
+        debug!("Found table {}!", id);
+        print!("{}", t.linked(&tables));
+    }
+    Ok(tables.into_iter().filter(|t| t.id() == id).collect())
+}
+
+// Reads every structure up to and including the End-of-Table
+// structure. Inactive structures are dropped here unless asked for, so
+// they don't turn up through handle references either.
+pub fn read_all_tables(
+    entrypoint: &entrypoint::Entrypoint,
+    show_inactive: bool,
+) -> Result<Vec<Table>, err::DMIParserError> {
+    let mut f = File::open(TABLES)?;
+    let scan = scan_tables(&mut f, entrypoint.table_size().into())?;
+    for d in scan.diagnostics.iter() {
+        warn!("{}", d);
+    }
+    Ok(filter_inactive(scan.tables, show_inactive))
+}
+
+pub fn filter_inactive(tables: Vec<Table>, show_inactive: bool) -> Vec<Table> {
+    if show_inactive {
+        return tables;
+    }
+    tables.into_iter().filter(|t| t.id() != INACTIVE).collect()
+}
 
-    for _i in 0..1000 {
+pub struct Scan {
+    pub tables: Vec<Table>,
+    // Problems with the table layout that don't stop it being read
+    pub diagnostics: Vec<String>,
+}
+
+// Walks the structures in table data of the given size, in the order
+// they appear
+pub fn scan_tables<R: Read + Seek>(
+    f: &mut R,
+    table_size: u64,
+) -> Result<Scan, err::DMIParserError> {
+    let mut scan = Scan {
+        tables: Vec::new(),
+        diagnostics: Vec::new(),
+    };
+    let mut loc = 0;
+    while loc < table_size {
+        let t = Table::read_from(f, loc)?;
         debug!(
             "Read table at position 0x{:x}, ID 0x{:02x}, Handle 0x{:04x}, Size 0x{:04x}",
             t.location(),
@@ -38,20 +93,136 @@ pub fn read_raw_table(
             t.handle(),
             t.size(),
         );
-        if t.id() == 127 {
-            warn!("Found End-of-table structure");
+        if t.size() < 4 {
+            scan.diagnostics
+                .push(format!("Malformed structure at 0x{:x}. Buggy firmware?", loc));
             break;
         }
-        if t.id() == id {
-            debug!("Found table {}!", id);
-            print!("{}", &t);
-            break;
+        loc = t.next_loc();
+        let id = t.id();
+        scan.tables.push(t);
+        if id == END_OF_TABLE {
+            debug!("Found End-of-Table structure, next byte at 0x{:x}", loc);
+            check_after_end_of_table(f, loc, table_size, &mut scan.diagnostics);
+            return Ok(scan);
         }
-        if t.next_loc() > table_size.into() {
-            warn!("Reached end of table");
-            break;
+    }
+    scan.diagnostics
+        .push(String::from("No End-of-Table structure found. Buggy firmware?"));
+    Ok(scan)
+}
+
+// Anything between the End-of-Table structure and the end of the table
+// is ignored, but is worth a diagnostic. Zero padding is common and is
+// not reported.
+fn check_after_end_of_table<R: Read + Seek>(
+    f: &mut R,
+    mut loc: u64,
+    table_size: u64,
+    diagnostics: &mut Vec<String>,
+) {
+    while loc < table_size {
+        let t = match Table::read_from(f, loc) {
+            Ok(t) => t,
+            Err(e) => {
+                diagnostics.push(format!(
+                    "Unreadable data after End-of-Table at 0x{:x}: {}",
+                    loc, e
+                ));
+                return;
+            }
+        };
+        if t.id() == 0 && t.size() == 0 {
+            return;
         }
-        t = Table::read_at(t.next_loc())?;
+        if t.size() < 4 {
+            diagnostics.push(format!(
+                "Malformed structure after End-of-Table at 0x{:x}",
+                loc
+            ));
+            return;
+        }
+        diagnostics.push(format!(
+            "Ignoring structure after End-of-Table at 0x{:x}: {}",
+            loc,
+            t.summary()
+        ));
+        loc = t.next_loc();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    // A structure with no formatted fields beyond the header and no
+    // strings
+    fn structure(id: u8, handle: u8) -> Vec<u8> {
+        vec![id, 0x4, handle, 0x0, 0x0, 0x0]
+    }
+
+    fn scan(data: &[u8]) -> Scan {
+        let mut f = Cursor::new(data.to_vec());
+        scan_tables(&mut f, data.len() as u64).unwrap()
+    }
+
+    #[test]
+    fn t_data_after_end_of_table() {
+        let mut data = structure(0, 0x1);
+        data.extend(structure(END_OF_TABLE, 0x2));
+        data.extend(structure(1, 0x3));
+        data.extend([0; 8].iter());
+        let s = scan(&data);
+        let ids: Vec<u8> = s.tables.iter().map(|t| t.id()).collect();
+        assert_eq!(ids, vec![0, END_OF_TABLE]);
+        assert_eq!(
+            s.diagnostics,
+            vec!["Ignoring structure after End-of-Table at 0xc: \
+                  Handle 0x0003, Type 1 (System Information)"]
+        );
+
+        // Zero padding after the terminator is normal
+        let mut data = structure(0, 0x1);
+        data.extend(structure(END_OF_TABLE, 0x2));
+        data.extend([0; 8].iter());
+        assert!(scan(&data).diagnostics.is_empty());
+    }
+
+    #[test]
+    fn t_missing_end_of_table() {
+        let mut data = structure(0, 0x1);
+        data.extend(structure(1, 0x2));
+        let s = scan(&data);
+        assert_eq!(s.tables.len(), 2);
+        assert_eq!(
+            s.diagnostics,
+            vec!["No End-of-Table structure found. Buggy firmware?"]
+        );
+
+        // Large tables are read to the end, not cut short
+        let mut data = Vec::new();
+        for i in 0..1500 {
+            data.extend(structure(8, i as u8));
+        }
+        data.extend(structure(END_OF_TABLE, 0xff));
+        let s = scan(&data);
+        assert_eq!(s.tables.len(), 1501);
+        assert!(s.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn t_inactive_structures() {
+        let mut data = structure(0, 0x1);
+        data.extend(structure(INACTIVE, 0x2));
+        data.extend(structure(END_OF_TABLE, 0x3));
+        let ids = |show| -> Vec<u8> {
+            filter_inactive(scan(&data).tables, show)
+                .iter()
+                .map(|t| t.id())
+                .collect()
+        };
+        assert_eq!(ids(false), vec![0, END_OF_TABLE]);
+        assert_eq!(ids(true), vec![0, INACTIVE, END_OF_TABLE]);
     }
-    Ok(t)
 }
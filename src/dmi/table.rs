@@ -21,12 +21,56 @@ use std::io;
 use std::io::prelude::*;
 use std::io::SeekFrom;
 
+mod probe;
 mod table0;
 mod table1;
 mod table2;
 mod table3;
+mod table4;
+mod table5;
+mod table6;
+mod table8;
+mod table10;
+mod table11;
+mod table12;
+mod table13;
+mod table14;
+mod table15;
+mod table17;
+mod table18;
+mod table21;
+mod table22;
+mod table23;
+mod table24;
+mod table25;
+mod table26;
+mod table27;
+mod table28;
+mod table29;
+mod table30;
+mod table32;
+mod table33;
+mod table34;
+mod table35;
+mod table36;
+mod table37;
+mod table38;
+mod table39;
+mod table40;
+mod table41;
+mod table42;
+mod table43;
+mod table44;
+mod table45;
+mod table46;
 
-const TABLES: &str = "/sys/firmware/dmi/tables/DMI";
+pub const TABLES: &str = "/sys/firmware/dmi/tables/DMI";
+// Structure types with a fixed meaning to the table reader rather than
+// to the decoders
+pub const INACTIVE: u8 = 126;
+pub const END_OF_TABLE: u8 = 127;
+
+const STRING_OUT_OF_RANGE: &str = "String index out of range. Buggy firmware?";
 
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -38,11 +82,50 @@ struct Data {
     pub strings: Vec<String>,
 }
 
-enum TableId {
+pub(crate) enum TableId {
     Bios,
     System,
     Baseboard,
     Chassis,
+    Processor,
+    MemoryController,
+    MemoryModule,
+    PortConnector,
+    OnboardDevices,
+    OemStrings,
+    ConfigurationOptions,
+    BiosLanguage,
+    GroupAssociations,
+    SystemEventLog,
+    MemoryDevice,
+    MemoryError32,
+    PointingDevice,
+    PortableBattery,
+    SystemReset,
+    HardwareSecurity,
+    PowerControls,
+    VoltageProbe,
+    CoolingDevice,
+    TemperatureProbe,
+    CurrentProbe,
+    RemoteAccess,
+    BootInformation,
+    MemoryError64,
+    ManagementDevice,
+    ManagementComponent,
+    ManagementThresholds,
+    MemoryChannel,
+    IpmiDevice,
+    PowerSupply,
+    AdditionalInformation,
+    OnboardDevicesExtended,
+    HostInterface,
+    TpmDevice,
+    ProcessorAdditional,
+    FirmwareInventory,
+    StringProperty,
+    Inactive,
+    EndOfTable,
     Other,
 }
 
@@ -51,13 +134,14 @@ pub struct Table {
     data: Data,
 }
 
-fn read_null_terminated_string(fh: &File) -> Result<String, io::Error> {
+// Reads one byte at a time on purpose: the caller relies on the file
+// position being left just past the terminating NUL, which a BufReader
+// would not preserve.
+#[allow(clippy::unbuffered_bytes)]
+fn read_null_terminated_string<R: Read>(fh: &mut R) -> Result<String, io::Error> {
     let mut r = String::new();
     for byte in fh.bytes() {
-        let byte = match byte {
-            Ok(byte) => byte,
-            Err(e) => return Err(e),
-        };
+        let byte = byte?;
         if byte == 0x0 {
             return Ok(r);
         }
@@ -67,16 +151,15 @@ fn read_null_terminated_string(fh: &File) -> Result<String, io::Error> {
 }
 
 impl Table {
-    pub fn read() -> Result<Table, err::DMIParserError> {
-        Table::read_at(0)
-    }
-
-    pub fn read_at(loc: u64) -> Result<Table, err::DMIParserError> {
-        let f = File::open(TABLES)?;
-        Table::read_fh_at(f, loc)
+    pub fn read_fh_at(mut f: File, location: u64) -> Result<Table, err::DMIParserError> {
+        Table::read_from(&mut f, location)
     }
 
-    pub fn read_fh_at(mut f: File, location: u64) -> Result<Table, err::DMIParserError> {
+    // Reads the structure at the given location of the table data
+    pub fn read_from<R: Read + Seek>(
+        f: &mut R,
+        location: u64,
+    ) -> Result<Table, err::DMIParserError> {
         f.seek(SeekFrom::Start(location))?;
         let mut buf = [0; 256];
         // read the header, which gives us the table ID and size
@@ -96,7 +179,7 @@ impl Table {
         let string_location = f.stream_position()?;
         let mut strings: Vec<String> = Vec::new();
         loop {
-            let s = match read_null_terminated_string(&f) {
+            let s = match read_null_terminated_string(f) {
                 Ok(s) => s,
                 Err(e) => {
                     error!("While reading strings: {}", e);
@@ -120,28 +203,53 @@ impl Table {
             bits: buf.to_vec(),
             strings,
         };
-        match res.bits[0] {
-            0 => Ok(Table {
-                id: TableId::Bios,
-                data: res,
-            }),
-            1 => Ok(Table {
-                id: TableId::System,
-                data: res,
-            }),
-            2 => Ok(Table {
-                id: TableId::Baseboard,
-                data: res,
-            }),
-            3 => Ok(Table {
-                id: TableId::Chassis,
-                data: res,
-            }),
-            _ => Ok(Table {
-                id: TableId::Other,
-                data: res,
-            }),
-        }
+        let id = match res.bits[0] {
+            0 => TableId::Bios,
+            1 => TableId::System,
+            2 => TableId::Baseboard,
+            3 => TableId::Chassis,
+            4 => TableId::Processor,
+            5 => TableId::MemoryController,
+            6 => TableId::MemoryModule,
+            8 => TableId::PortConnector,
+            10 => TableId::OnboardDevices,
+            11 => TableId::OemStrings,
+            12 => TableId::ConfigurationOptions,
+            13 => TableId::BiosLanguage,
+            14 => TableId::GroupAssociations,
+            15 => TableId::SystemEventLog,
+            17 => TableId::MemoryDevice,
+            18 => TableId::MemoryError32,
+            21 => TableId::PointingDevice,
+            22 => TableId::PortableBattery,
+            23 => TableId::SystemReset,
+            24 => TableId::HardwareSecurity,
+            25 => TableId::PowerControls,
+            26 => TableId::VoltageProbe,
+            27 => TableId::CoolingDevice,
+            28 => TableId::TemperatureProbe,
+            29 => TableId::CurrentProbe,
+            30 => TableId::RemoteAccess,
+            32 => TableId::BootInformation,
+            33 => TableId::MemoryError64,
+            34 => TableId::ManagementDevice,
+            35 => TableId::ManagementComponent,
+            36 => TableId::ManagementThresholds,
+            37 => TableId::MemoryChannel,
+            38 => TableId::IpmiDevice,
+            39 => TableId::PowerSupply,
+            40 => TableId::AdditionalInformation,
+            41 => TableId::OnboardDevicesExtended,
+            42 => TableId::HostInterface,
+            43 => TableId::TpmDevice,
+            44 => TableId::ProcessorAdditional,
+            45 => TableId::FirmwareInventory,
+            46 => TableId::StringProperty,
+            INACTIVE => TableId::Inactive,
+            END_OF_TABLE => TableId::EndOfTable,
+            _ => TableId::Other,
+        };
+        Ok(Table { id, data: res })
     }
 
     pub fn id(&self) -> u8 {
@@ -161,6 +269,94 @@ impl Table {
         (high << 8) | low
     }
 
+    // Reads a little-endian WORD field at the given offset
+    pub fn word(&self, offset: usize) -> u16 {
+        word_at(&self.data.bits, offset)
+    }
+
+    // Reads a little-endian DWORD field at the given offset
+    pub fn dword(&self, offset: usize) -> u32 {
+        dword_at(&self.data.bits, offset)
+    }
+
+    // Reads a little-endian QWORD field at the given offset
+    pub fn qword(&self, offset: usize) -> u64 {
+        let mut bytes: [u8; 8] = [0; 8];
+        bytes.clone_from_slice(&self.data.bits[offset..offset + 8]);
+        u64::from_le_bytes(bytes)
+    }
+
+    // Returns up to `count` fixed-size records starting at `start`,
+    // stopping early if a record would run past the end of the
+    // structure
+    pub fn records(&self, start: usize, count: usize, record_len: usize) -> Vec<&[u8]> {
+        let end = usize::from(self.size()).min(self.data.bits.len());
+        let mut r = Vec::new();
+        for i in 0..count {
+            let pos = start + i * record_len;
+            if record_len == 0 || pos + record_len > end {
+                break;
+            }
+            r.push(&self.data.bits[pos..pos + record_len]);
+        }
+        r
+    }
+
+    // A one-line description of this structure, used when another
+    // structure refers to it by handle
+    pub fn summary(&self) -> String {
+        let mut r = format!(
+            "Handle 0x{:04x}, Type {} ({})",
+            self.handle(),
+            self.id(),
+            type_name(self.id())
+        );
+        if let Some(offset) = designation_offset(self.id()) {
+            if self.size() > offset {
+                let idx: usize = self.data.bits[usize::from(offset)].into();
+                r.push_str(&format!(": {}", self.string_value(idx)));
+            }
+        }
+        r
+    }
+
+    // Wraps this structure with the rest of the SMBIOS table so that
+    // handle references can be resolved when it is printed
+    pub fn linked<'a>(&'a self, tables: &'a [Table]) -> Linked<'a> {
+        Linked {
+            table: self,
+            tables,
+        }
+    }
+
+    fn fmt_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        self.fmt_type_links(f, tables)?;
+        // Any structure may be the parent of Type 46 string properties
+        self.fmt_string_properties(f, tables)
+    }
+
+    fn fmt_type_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        match &self.id {
+            TableId::Processor => self.fmt_table4_links(f, tables),
+            TableId::MemoryController => self.fmt_table5_links(f, tables),
+            TableId::GroupAssociations => self.fmt_table14_links(f, tables),
+            TableId::MemoryDevice => self.fmt_table17_links(f, tables),
+            TableId::MemoryError32 | TableId::MemoryError64 => {
+                self.fmt_memory_error_links(f, tables)
+            }
+            TableId::CoolingDevice => self.fmt_table27_links(f, tables),
+            TableId::ManagementDevice => self.fmt_table34_links(f, tables),
+            TableId::ManagementComponent => self.fmt_table35_links(f, tables),
+            TableId::MemoryChannel => self.fmt_table37_links(f, tables),
+            TableId::PowerSupply => self.fmt_table39_links(f, tables),
+            TableId::AdditionalInformation => self.fmt_table40_links(f, tables),
+            TableId::ProcessorAdditional => self.fmt_table44_links(f, tables),
+            TableId::FirmwareInventory => self.fmt_table45_links(f, tables),
+            TableId::StringProperty => self.fmt_table46_links(f, tables),
+            _ => Ok(()),
+        }
+    }
+
     pub fn strings(&self) -> &Vec<String> {
         &self.data.strings
     }
@@ -173,25 +369,163 @@ impl Table {
         self.data.next_loc
     }
 
-    pub fn fmt_str(&self, f: &mut fmt::Formatter<'_>, index: u8, label: &str) -> fmt::Result {
+    // Returns the string with the given (1-based) string number, or a
+    // placeholder describing why no string is available.
+    pub fn string_value(&self, idx: usize) -> &str {
         let mut val: &str = "Unspecified";
-
-        debug!("formatting string from index {}", index);
-        let idx: usize = (self.data.bits[usize::from(index)]).into();
-        debug!("String is at location {}", idx);
         if idx > 0 {
             if self.data.strings.len() >= idx {
                 if !self.data.strings[idx - 1].is_empty() {
                     val = &self.data.strings[idx - 1];
                 }
             } else {
-                val = "String index out of range. Buggy firmware?"
+                val = STRING_OUT_OF_RANGE
             }
         }
-        writeln!(f, "{}: {}", label, val)
+        val
+    }
+
+    // Types 11 and 12 are a count at 0x4 followed by that many strings
+    pub fn string_set_count(&self) -> usize {
+        match self.size() {
+            0..=4 => 0,
+            _ => self.data.bits[0x4].into(),
+        }
+    }
+
+    // Returns string n (1-based) of a Type 11 or 12 string set, if the
+    // count covers it and the firmware actually provided it
+    pub fn string_set_value(&self, n: usize) -> Option<&str> {
+        if n == 0 || n > self.string_set_count() {
+            return None;
+        }
+        self.data.strings.get(n - 1).map(|s| s.as_str())
+    }
+
+    fn fmt_string_set(&self, f: &mut fmt::Formatter<'_>, label: &str) -> fmt::Result {
+        for n in 1..=self.string_set_count() {
+            let s = self.string_set_value(n).unwrap_or(STRING_OUT_OF_RANGE);
+            writeln!(f, "{} {}: {}", label, n, s)?;
+        }
+        Ok(())
+    }
+
+    pub fn fmt_str(&self, f: &mut fmt::Formatter<'_>, index: u8, label: &str) -> fmt::Result {
+        debug!("formatting string from index {}", index);
+        let idx: usize = (self.data.bits[usize::from(index)]).into();
+        debug!("String is at location {}", idx);
+        writeln!(f, "{}: {}", label, self.string_value(idx))
     }
 }
 
+pub fn find_handle(tables: &[Table], handle: u16) -> Option<&Table> {
+    tables.iter().find(|t| t.handle() == handle)
+}
+
+// Structure names from section 7 of SMBIOS spec 3.5.0
+pub fn type_name(id: u8) -> &'static str {
+    match id {
+        0 => "BIOS Information",
+        1 => "System Information",
+        2 => "Baseboard Information",
+        3 => "System Enclosure or Chassis",
+        4 => "Processor Information",
+        5 => "Memory Controller Information",
+        6 => "Memory Module Information",
+        7 => "Cache Information",
+        8 => "Port Connector Information",
+        9 => "System Slots",
+        10 => "On Board Devices Information",
+        11 => "OEM Strings",
+        12 => "System Configuration Options",
+        13 => "BIOS Language Information",
+        14 => "Group Associations",
+        15 => "System Event Log",
+        16 => "Physical Memory Array",
+        17 => "Memory Device",
+        18 => "32-Bit Memory Error Information",
+        19 => "Memory Array Mapped Address",
+        20 => "Memory Device Mapped Address",
+        21 => "Built-in Pointing Device",
+        22 => "Portable Battery",
+        23 => "System Reset",
+        24 => "Hardware Security",
+        25 => "System Power Controls",
+        26 => "Voltage Probe",
+        27 => "Cooling Device",
+        28 => "Temperature Probe",
+        29 => "Electrical Current Probe",
+        30 => "Out-of-Band Remote Access",
+        31 => "Boot Integrity Services (BIS) Entry Point",
+        32 => "System Boot Information",
+        33 => "64-Bit Memory Error Information",
+        34 => "Management Device",
+        35 => "Management Device Component",
+        36 => "Management Device Threshold Data",
+        37 => "Memory Channel",
+        38 => "IPMI Device Information",
+        39 => "System Power Supply",
+        40 => "Additional Information",
+        41 => "Onboard Devices Extended Information",
+        42 => "Management Controller Host Interface",
+        43 => "TPM Device",
+        44 => "Processor Additional Information",
+        45 => "Firmware Inventory Information",
+        46 => "String Property",
+        INACTIVE => "Inactive",
+        END_OF_TABLE => "End-of-Table",
+        128..=255 => "OEM-specific",
+        _ => "Unknown",
+    }
+}
+
+// Offset of the string that best identifies a structure of the given
+// type, e.g. a socket designation or device locator
+fn designation_offset(id: u8) -> Option<u8> {
+    match id {
+        0..=2 => Some(0x5),
+        3 | 4 | 6 | 7 | 8 | 9 | 14 | 22 | 26 | 28 | 29 | 34 | 35 | 41 | 45 => Some(0x4),
+        17 => Some(0x10),
+        27 => Some(0xe),
+        39 => Some(0x6),
+        43 => Some(0x12),
+        _ => None,
+    }
+}
+
+// Reads a little-endian WORD from a byte slice, for fields inside
+// variable-length records that don't start at a fixed structure offset
+pub fn word_at(b: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([b[offset], b[offset + 1]])
+}
+
+// Reads a little-endian DWORD from a byte slice
+pub fn dword_at(b: &[u8], offset: usize) -> u32 {
+    let mut bytes: [u8; 4] = [0; 4];
+    bytes.clone_from_slice(&b[offset..offset + 4]);
+    u32::from_le_bytes(bytes)
+}
+
+// Decodes a packed BCD byte, returning None if either nibble is not a
+// decimal digit
+pub fn decode_bcd(b: u8) -> Option<u8> {
+    let (high, low) = (b >> 4, b & 0xf);
+    if high > 9 || low > 9 {
+        return None;
+    }
+    Some(high * 10 + low)
+}
+
+// Formats a 16 byte UUID in the SMBIOS byte order, where the first
+// three fields are little-endian (section 7.2.1)
+pub fn uuid_string(b: &[u8]) -> String {
+    format!(
+        "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+        b[3], b[2], b[1], b[0], b[5], b[4], b[7], b[6],
+        b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15]
+    )
+}
+
 fn decode_byte(f: &mut fmt::Formatter<'_>, b: u8, bit_strings: &[(u8, &str)]) -> fmt::Result {
     for bit in bit_strings.iter() {
         if (b & bit.0) != 0 {
@@ -201,10 +535,71 @@ fn decode_byte(f: &mut fmt::Formatter<'_>, b: u8, bit_strings: &[(u8, &str)]) ->
     Ok(())
 }
 
+// Like decode_byte, but for fields wider than one bit. Each field is
+// given as (shift, width, label), and its value is used as an index
+// into value_strings.
+fn decode_fields(
+    f: &mut fmt::Formatter<'_>,
+    b: u8,
+    fields: &[(u8, u8, &str)],
+    value_strings: &[&str],
+) -> fmt::Result {
+    for field in fields.iter() {
+        let v: usize = ((b >> field.0) & ((1 << field.1) - 1)).into();
+        match value_strings.get(v) {
+            Some(s) => writeln!(f, "{}: {}", field.2, s)?,
+            None => writeln!(f, "{}: Unrecognized value {}. Probably a bug.", field.2, v)?,
+        }
+    }
+    Ok(())
+}
+
+// Builds a structure from raw bytes and strings, for tests that need
+// several structures to resolve handles against
+#[cfg(test)]
+pub(crate) fn table(id: TableId, bits: Vec<u8>, strings: Vec<String>) -> Table {
+    let d = Data {
+        location: 0,
+        string_location: 0,
+        next_loc: 0,
+        bits,
+        strings,
+    };
+    Table { id, data: d }
+}
+
 fn fmt_unknown_table(f: &mut fmt::Formatter<'_>, data: &[u8]) -> fmt::Result {
     writeln!(f, "Unhandled table {}", data[0])
 }
 
+pub struct Linked<'a> {
+    table: &'a Table,
+    tables: &'a [Table],
+}
+
+impl fmt::Display for Linked<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Type 40 entries are shown next to the fields they refer to
+        self.table.fmt_with_additional_info(f, self.tables)?;
+        self.table.fmt_links(f, self.tables)
+    }
+}
+
+impl Table {
+    // Field offsets the decoder can point at in its output. Types
+    // without a list show Type 40 entries after the structure.
+    pub fn field_labels(&self) -> &'static [(u8, &'static str)] {
+        match &self.id {
+            TableId::Bios => table0::TABLE0_FIELDS,
+            TableId::System => table1::TABLE1_FIELDS,
+            TableId::Baseboard => table2::TABLE2_FIELDS,
+            TableId::Chassis => table3::TABLE3_FIELDS,
+            TableId::PortConnector => table8::TABLE8_FIELDS,
+            _ => &[],
+        }
+    }
+}
+
 impl fmt::Display for Table {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.id {
@@ -212,6 +607,45 @@ impl fmt::Display for Table {
             TableId::System => self.fmt_table1(f),
             TableId::Baseboard => self.fmt_table2(f),
             TableId::Chassis => self.fmt_table3(f),
+            TableId::Processor => self.fmt_table4(f),
+            TableId::MemoryController => self.fmt_table5(f),
+            TableId::MemoryModule => self.fmt_table6(f),
+            TableId::PortConnector => self.fmt_table8(f),
+            TableId::OnboardDevices => self.fmt_table10(f),
+            TableId::OemStrings => self.fmt_table11(f),
+            TableId::ConfigurationOptions => self.fmt_table12(f),
+            TableId::BiosLanguage => self.fmt_table13(f),
+            TableId::GroupAssociations => self.fmt_table14(f),
+            TableId::SystemEventLog => self.fmt_table15(f),
+            TableId::MemoryDevice => self.fmt_table17(f),
+            TableId::MemoryError32 => self.fmt_table18(f),
+            TableId::PointingDevice => self.fmt_table21(f),
+            TableId::PortableBattery => self.fmt_table22(f),
+            TableId::SystemReset => self.fmt_table23(f),
+            TableId::HardwareSecurity => self.fmt_table24(f),
+            TableId::PowerControls => self.fmt_table25(f),
+            TableId::VoltageProbe => self.fmt_table26(f),
+            TableId::CoolingDevice => self.fmt_table27(f),
+            TableId::TemperatureProbe => self.fmt_table28(f),
+            TableId::CurrentProbe => self.fmt_table29(f),
+            TableId::RemoteAccess => self.fmt_table30(f),
+            TableId::BootInformation => self.fmt_table32(f),
+            TableId::MemoryError64 => self.fmt_table33(f),
+            TableId::ManagementDevice => self.fmt_table34(f),
+            TableId::ManagementComponent => self.fmt_table35(f),
+            TableId::ManagementThresholds => self.fmt_table36(f),
+            TableId::MemoryChannel => self.fmt_table37(f),
+            TableId::IpmiDevice => self.fmt_table38(f),
+            TableId::PowerSupply => self.fmt_table39(f),
+            TableId::AdditionalInformation => self.fmt_table40(f),
+            TableId::OnboardDevicesExtended => self.fmt_table41(f),
+            TableId::HostInterface => self.fmt_table42(f),
+            TableId::TpmDevice => self.fmt_table43(f),
+            TableId::ProcessorAdditional => self.fmt_table44(f),
+            TableId::FirmwareInventory => self.fmt_table45(f),
+            TableId::StringProperty => self.fmt_table46(f),
+            TableId::Inactive => writeln!(f, "Table 126 (Inactive)"),
+            TableId::EndOfTable => writeln!(f, "Table 127 (End-of-Table)"),
             _ => fmt_unknown_table(f, &self.data.bits),
         }
     }
@@ -0,0 +1,155 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+// Helpers shared by the voltage (Type 26), temperature (Type 28) and
+// electrical current (Type 29) probe structures, which have the same
+// layout and differ only in their units.
+
+use crate::dmi::table::table26::VOLTAGE;
+use crate::dmi::table::table27::SPEED;
+use crate::dmi::table::table28::TEMPERATURE;
+use crate::dmi::table::table29::CURRENT;
+use crate::dmi::table::Table;
+use std::fmt;
+
+const PROBE_UNKNOWN: u16 = 0x8000;
+
+pub struct ProbeUnits {
+    // Maximum, minimum, tolerance and nominal values are reported in
+    // 1/value_divisor of value_unit
+    pub value_divisor: f64,
+    pub value_precision: usize,
+    pub value_unit: &'static str,
+    // Resolution is reported in 1/resolution_divisor of resolution_unit
+    pub resolution_divisor: f64,
+    pub resolution_precision: usize,
+    pub resolution_unit: &'static str,
+}
+
+// Units for the given probe or cooling device structure type
+pub fn probe_units(id: u8) -> Option<&'static ProbeUnits> {
+    match id {
+        26 => Some(&VOLTAGE),
+        27 => Some(&SPEED),
+        28 => Some(&TEMPERATURE),
+        29 => Some(&CURRENT),
+        _ => None,
+    }
+}
+
+pub fn probe_status(b: u8) -> &'static str {
+    match b >> 5 {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "OK",
+        0x4 => "Non-critical",
+        0x5 => "Critical",
+        0x6 => "Non-recoverable",
+        _ => "Unrecognized status. Probably a bug.",
+    }
+}
+
+// Voltage and current probes only define locations up to Add-in Card;
+// the board and backplane locations are for temperature probes
+pub fn probe_location(id: u8, b: u8) -> &'static str {
+    match b & 0x1f {
+        0xc..=0xf if id != 28 => "Unrecognized location. Probably a bug.",
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "Processor",
+        0x4 => "Disk",
+        0x5 => "Peripheral Bay",
+        0x6 => "System Management Module",
+        0x7 => "Motherboard",
+        0x8 => "Memory Module",
+        0x9 => "Processor Module",
+        0xa => "Power Unit",
+        0xb => "Add-in Card",
+        0xc => "Front Panel Board",
+        0xd => "Back Panel Board",
+        0xe => "Power System Board",
+        0xf => "Drive Back Plane",
+        _ => "Unrecognized location. Probably a bug.",
+    }
+}
+
+// Scales a raw probe reading, which is signed, into real units.
+// Returns None for the "unknown" sentinel.
+pub fn probe_value(raw: u16, divisor: f64) -> Option<f64> {
+    match raw {
+        PROBE_UNKNOWN => None,
+        v => Some(f64::from(v as i16) / divisor),
+    }
+}
+
+// Resolution and accuracy are unsigned, but share the "unknown" sentinel
+pub fn probe_unsigned_value(raw: u16, divisor: f64) -> Option<f64> {
+    match raw {
+        PROBE_UNKNOWN => None,
+        v => Some(f64::from(v) / divisor),
+    }
+}
+
+impl Table {
+    fn fmt_probe_value(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        offset: usize,
+        label: &str,
+        prefix: &str,
+        units: &ProbeUnits,
+    ) -> fmt::Result {
+        match probe_value(self.word(offset), units.value_divisor) {
+            None => writeln!(f, "{}: Unknown", label),
+            Some(v) => writeln!(
+                f,
+                "{}: {}{:.*} {}",
+                label, prefix, units.value_precision, v, units.value_unit
+            ),
+        }
+    }
+
+    pub fn fmt_probe(&self, f: &mut fmt::Formatter<'_>, units: &ProbeUnits) -> fmt::Result {
+        let len = self.size();
+        if len < 0x14 {
+            return Ok(());
+        }
+        let b = self.data.bits[0x5];
+        self.fmt_str(f, 0x4, "Description")?;
+        writeln!(f, "Location: {}", probe_location(self.id(), b))?;
+        writeln!(f, "Status: {}", probe_status(b))?;
+        self.fmt_probe_value(f, 0x6, "Maximum Value", "", units)?;
+        self.fmt_probe_value(f, 0x8, "Minimum Value", "", units)?;
+        match probe_unsigned_value(self.word(0xa), units.resolution_divisor) {
+            None => writeln!(f, "Resolution: Unknown")?,
+            Some(v) => writeln!(
+                f,
+                "Resolution: {:.*} {}",
+                units.resolution_precision, v, units.resolution_unit
+            )?,
+        }
+        self.fmt_probe_value(f, 0xc, "Tolerance", "+/- ", units)?;
+        match probe_unsigned_value(self.word(0xe), 100.0) {
+            None => writeln!(f, "Accuracy: Unknown")?,
+            Some(v) => writeln!(f, "Accuracy: +/- {:.2}%", v)?,
+        }
+        writeln!(f, "OEM-specific Information: 0x{:08x}", self.dword(0x10))?;
+        if len >= 0x16 {
+            self.fmt_probe_value(f, 0x14, "Nominal Value", "", units)?;
+        }
+        Ok(())
+    }
+}
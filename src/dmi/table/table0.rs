@@ -19,6 +19,19 @@ use crate::dmi::table::decode_byte;
 use crate::dmi::table::Table;
 use std::fmt;
 
+// Output line prefix for each field offset, used to show Type 40
+// entries next to the field they refer to
+pub const TABLE0_FIELDS: &[(u8, &str)] = &[
+    (0x4, "BIOS Vendor:"),
+    (0x5, "BIOS Version:"),
+    (0x6, "BIOS starts at memory location"),
+    (0x8, "BIOS Release Date:"),
+    (0x9, "BIOS size is"),
+    (0x14, "BIOS Revision:"),
+    (0x16, "Firmware Revision:"),
+    (0x18, "BIOS ROM Size:"),
+];
+
 impl Table {
     fn fmt_vendor(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.fmt_str(f, 4, "BIOS Vendor")
@@ -17,6 +17,19 @@
 use crate::dmi::table::Table;
 use std::fmt;
 
+// Output line prefix for each field offset, used to show Type 40
+// entries next to the field they refer to
+pub const TABLE1_FIELDS: &[(u8, &str)] = &[
+    (0x4, "System Manufacturer:"),
+    (0x5, "Product Name:"),
+    (0x6, "Product Version:"),
+    (0x7, "Product Serial:"),
+    (0x8, "UUID:"),
+    (0x18, "Wake reason:"),
+    (0x19, "Product SKU:"),
+    (0x1a, "Product Family:"),
+];
+
 impl Table {
     fn fmt_manufacturer(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.fmt_str(f, 0x04, "System Manufacturer")
@@ -0,0 +1,109 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use std::fmt;
+
+// Shared by Type 10 and Type 41. Values above 0xa are only defined
+// for Type 41.
+pub fn onboard_device_type(b: u8) -> &'static str {
+    match b & 127 {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "Video",
+        0x4 => "SCSI Controller",
+        0x5 => "Ethernet",
+        0x6 => "Token Ring",
+        0x7 => "Sound",
+        0x8 => "PATA Controller",
+        0x9 => "SATA Controller",
+        0xa => "SAS Controller",
+        0xb => "Wireless LAN",
+        0xc => "Bluetooth",
+        0xd => "WWAN",
+        0xe => "eMMC (embedded Multi-Media Controller)",
+        0xf => "NVMe Controller",
+        0x10 => "UFS Controller",
+        _ => "Unrecognized device type. Probably a bug.",
+    }
+}
+
+pub fn onboard_device_status(b: u8) -> &'static str {
+    if b >> 7 == 1 {
+        "Enabled"
+    } else {
+        "Disabled"
+    }
+}
+
+impl Table {
+    pub fn fmt_table10(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 10 (On Board Devices Information)")?;
+        let len: u8 = self.size();
+        // Each device takes two bytes: a type byte and a description string
+        let count = len.saturating_sub(4) / 2;
+        writeln!(f, "Devices: {}", count)?;
+        for i in 0..count {
+            let pos = 4 + 2 * i;
+            let b = self.data.bits[usize::from(pos)];
+            writeln!(f, "On Board Device {}:", i + 1)?;
+            writeln!(f, "  Type: {}", onboard_device_type(b))?;
+            writeln!(f, "  Status: {}", onboard_device_status(b))?;
+            self.fmt_str(f, pos + 1, "  Description")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table10() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                10,   // type
+                0x8,  // length, two devices
+                0x20, // handle (2 bytes)
+                0x0,
+                128 | 0x3, // enabled video device
+                1,         // description string
+                0x5,       // disabled ethernet device
+                2,         // description string
+            ]
+            .to_vec(),
+            strings: [String::from("Onboard VGA"), String::from("Onboard LAN")].to_vec(),
+        };
+        let table = Table {
+            id: TableId::OnboardDevices,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Devices: 2"));
+        assert!(r.contains(
+            "On Board Device 1:\n  Type: Video\n  Status: Enabled\n  Description: Onboard VGA"
+        ));
+        assert!(r.contains(
+            "On Board Device 2:\n  Type: Ethernet\n  Status: Disabled\n  Description: Onboard LAN"
+        ));
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use std::fmt;
+
+impl Table {
+    // Returns OEM string number n (1-based), if this structure has one
+    pub fn oem_string(&self, n: usize) -> Option<&str> {
+        self.string_set_value(n)
+    }
+
+    // Returns the (1-based) index and value of every OEM string that
+    // starts with the given prefix
+    pub fn oem_strings_with_prefix(&self, prefix: &str) -> Vec<(usize, &str)> {
+        (1..=self.string_set_count())
+            .filter_map(|n| self.oem_string(n).map(|s| (n, s)))
+            .filter(|(_, s)| s.starts_with(prefix))
+            .collect()
+    }
+
+    pub fn fmt_table11(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 11 (OEM Strings)")?;
+        writeln!(f, "String count: {}", self.string_set_count())?;
+        self.fmt_string_set(f, "String")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn oem_table() -> Table {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                11,   // type
+                0x5,  // length
+                0x40, // handle (2 bytes)
+                0x0,
+                3, // string count
+            ]
+            .to_vec(),
+            strings: [
+                String::from("Dell System"),
+                String::from("io.systemd.credential:foo=bar"),
+                String::from("io.systemd.credential:baz=qux"),
+            ]
+            .to_vec(),
+        };
+        Table {
+            id: TableId::OemStrings,
+            data: d,
+        }
+    }
+
+    #[test]
+    fn test_decode_table11() {
+        let table = oem_table();
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("String count: 3"));
+        assert!(r.contains("String 1: Dell System"));
+        assert!(r.contains("String 3: io.systemd.credential:baz=qux"));
+    }
+
+    #[test]
+    fn test_oem_string_queries() {
+        let table = oem_table();
+        assert_eq!(table.oem_string(1), Some("Dell System"));
+        assert_eq!(table.oem_string(0), None);
+        assert_eq!(table.oem_string(4), None);
+        let matches = table.oem_strings_with_prefix("io.systemd.credential:");
+        assert_eq!(
+            matches,
+            vec![
+                (2, "io.systemd.credential:foo=bar"),
+                (3, "io.systemd.credential:baz=qux")
+            ]
+        );
+    }
+}
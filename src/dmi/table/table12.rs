@@ -0,0 +1,59 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use std::fmt;
+
+impl Table {
+    pub fn fmt_table12(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 12 (System Configuration Options)")?;
+        writeln!(f, "Option count: {}", self.string_set_count())?;
+        self.fmt_string_set(f, "Option")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table12() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                12,   // type
+                0x5,  // length
+                0x41, // handle (2 bytes)
+                0x0,
+                2, // string count
+            ]
+            .to_vec(),
+            strings: [String::from("JP1: CMOS clear")].to_vec(),
+        };
+        let table = Table {
+            id: TableId::ConfigurationOptions,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Option count: 2"));
+        assert!(r.contains("Option 1: JP1: CMOS clear"));
+        assert!(r.contains("Option 2: String index out of range. Buggy firmware?"));
+    }
+}
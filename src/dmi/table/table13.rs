@@ -0,0 +1,105 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use crate::dmi::table::STRING_OUT_OF_RANGE;
+use std::fmt;
+
+impl Table {
+    fn fmt_language_format(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The flags byte is reserved (zero) in SMBIOS 2.0, which
+        // matches the long format
+        let t: &str = match self.data.bits[0x5] & 1 {
+            1 => "Abbreviated",
+            _ => "Long",
+        };
+        writeln!(f, "Language Format: {}", t)
+    }
+
+    pub fn fmt_table13(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 13 (BIOS Language Information)")?;
+        let len: u8 = self.size();
+        if len < 0x16 {
+            return Ok(());
+        }
+        let count: usize = self.data.bits[0x4].into();
+        let current: usize = self.data.bits[0x15].into();
+        writeln!(f, "Installable Languages: {}", count)?;
+        self.fmt_language_format(f)?;
+        for i in 1..=count {
+            if i == current {
+                writeln!(f, "Language {}: {} (current)", i, self.string_value(i))?;
+            } else {
+                writeln!(f, "Language {}: {}", i, self.string_value(i))?;
+            }
+        }
+        // The current language must be one of the installable
+        // languages, even if the firmware supplied extra strings
+        let val: &str = match current {
+            0 => "Unspecified",
+            c if c > count => STRING_OUT_OF_RANGE,
+            c => self.string_value(c),
+        };
+        writeln!(f, "Current Language: {} ({})", current, val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn language_table(current: u8) -> Table {
+        let mut bits = vec![0; 0x16];
+        bits[0] = 13; // type
+        bits[1] = 0x16; // length
+        bits[2] = 0x50; // handle
+        bits[0x4] = 2; // installable languages
+        bits[0x5] = 1; // abbreviated format
+        bits[0x15] = current; // current language string
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits,
+            strings: [String::from("enUS"), String::from("frFR")].to_vec(),
+        };
+        Table {
+            id: TableId::BiosLanguage,
+            data: d,
+        }
+    }
+
+    #[test]
+    fn test_decode_table13() {
+        let r = format!("{}", language_table(2));
+        println!("{}", r);
+        assert!(r.contains("Installable Languages: 2"));
+        assert!(r.contains("Language Format: Abbreviated"));
+        assert!(r.contains("Language 1: enUS\n"));
+        assert!(r.contains("Language 2: frFR (current)"));
+        assert!(r.contains("Current Language: 2 (frFR)"));
+    }
+
+    #[test]
+    fn test_decode_table13_bad_current_language() {
+        let r = format!("{}", language_table(3));
+        println!("{}", r);
+        assert!(!r.contains("(current)"));
+        assert!(r.contains("Current Language: 3 (String index out of range. Buggy firmware?)"));
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::find_handle;
+use crate::dmi::table::type_name;
+use crate::dmi::table::Table;
+use std::fmt;
+
+impl Table {
+    // Each item is a structure type byte followed by its handle
+    fn group_items(&self) -> Vec<(u8, u16)> {
+        let count = usize::from(self.size()).saturating_sub(5) / 3;
+        self.records(0x5, count, 3)
+            .iter()
+            .map(|r| (r[0], u16::from_le_bytes([r[1], r[2]])))
+            .collect()
+    }
+
+    fn fmt_group_name(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_str(f, 0x4, "Group Name")
+    }
+
+    pub fn fmt_table14(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 14 (Group Associations)")?;
+        if self.size() < 0x5 {
+            return Ok(());
+        }
+        self.fmt_group_name(f)?;
+        let items = self.group_items();
+        writeln!(f, "Items: {}", items.len())?;
+        for (i, (t, handle)) in items.iter().enumerate() {
+            writeln!(
+                f,
+                "Item {}: Type {} ({}), handle 0x{:04x}",
+                i + 1,
+                t,
+                type_name(*t),
+                handle
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn fmt_table14_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        let items = self.group_items();
+        if items.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "Group members:")?;
+        for (t, handle) in items.iter() {
+            match find_handle(tables, *handle) {
+                Some(member) if member.id() == *t => writeln!(f, "  + {}", member.summary())?,
+                Some(member) => writeln!(
+                    f,
+                    "  + {} (expected type {}. Buggy firmware?)",
+                    member.summary(),
+                    t
+                )?,
+                None => writeln!(f, "  + Handle 0x{:04x}: structure not found", handle)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::table;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn group_table() -> Table {
+        table(
+            TableId::GroupAssociations,
+            [
+                14,   // type
+                0xb,  // length, two items
+                0x60, // handle (2 bytes)
+                0x0,
+                1,    // group name string
+                4,    // item 1 type
+                0x40, // item 1 handle (2 bytes)
+                0x0,
+                7,    // item 2 type
+                0x41, // item 2 handle (2 bytes)
+                0x0,
+            ]
+            .to_vec(),
+            [String::from("Cpu Module")].to_vec(),
+        )
+    }
+
+    #[test]
+    fn test_decode_table14() {
+        let r = format!("{}", group_table());
+        println!("{}", r);
+        assert!(r.contains("Group Name: Cpu Module"));
+        assert!(r.contains("Items: 2"));
+        assert!(r.contains("Item 1: Type 4 (Processor Information), handle 0x0040"));
+        assert!(r.contains("Item 2: Type 7 (Cache Information), handle 0x0041"));
+        assert!(!r.contains("Group members:"));
+    }
+
+    #[test]
+    fn test_decode_table14_linked() {
+        let group = group_table();
+        let mut cpu_bits = vec![0; 0x1a];
+        cpu_bits[0] = 4;
+        cpu_bits[1] = 0x1a;
+        cpu_bits[2] = 0x40;
+        cpu_bits[4] = 1; // socket designation
+        let tables = [
+            table(TableId::Other, cpu_bits, [String::from("CPU0")].to_vec()),
+            group_table(),
+        ];
+        let r = format!("{}", group.linked(&tables));
+        println!("{}", r);
+        assert!(r.contains("Group members:"));
+        assert!(r.contains("  + Handle 0x0040, Type 4 (Processor Information): CPU0"));
+        assert!(r.contains("  + Handle 0x0041: structure not found"));
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::eventlog::event_type;
+use crate::dmi::table::decode_byte;
+use crate::dmi::table::Table;
+use std::fmt;
+
+fn variable_data_format(b: u8) -> &'static str {
+    match b {
+        0x0 => "None",
+        0x1 => "Handle",
+        0x2 => "Multiple-Event",
+        0x3 => "Multiple-Event Handle",
+        0x4 => "POST Results Bitmap",
+        0x5 => "System Management Type",
+        0x6 => "Multiple-Event System Management Type",
+        0x80..=0xff => "OEM assigned",
+        _ => "Unused",
+    }
+}
+
+impl Table {
+    pub fn log_area_length(&self) -> u16 {
+        self.word(0x4)
+    }
+
+    pub fn log_header_offset(&self) -> u16 {
+        self.word(0x6)
+    }
+
+    pub fn log_data_offset(&self) -> u16 {
+        self.word(0x8)
+    }
+
+    pub fn log_header_format(&self) -> u8 {
+        self.data.bits[0x14]
+    }
+
+    // Physical address of the log area, if the firmware uses the
+    // memory-mapped access method
+    pub fn log_area_address(&self) -> Option<u64> {
+        match self.data.bits[0xa] {
+            0x3 => Some(self.dword(0x10).into()),
+            _ => None,
+        }
+    }
+
+    fn fmt_access_method(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let method = self.data.bits[0xa];
+        let t: &str = match method {
+            0x0 => "Indexed I/O, one 8-bit index port, one 8-bit data port",
+            0x1 => "Indexed I/O, two 8-bit index ports, one 8-bit data port",
+            0x2 => "Indexed I/O, one 16-bit index port, one 8-bit data port",
+            0x3 => "Memory-mapped physical 32-bit address",
+            0x4 => "General-purpose non-volatile data functions",
+            0x80..=0xff => "OEM-specific",
+            _ => "Unrecognized access method. Probably a bug.",
+        };
+        writeln!(f, "Access Method: {}", t)?;
+        match method {
+            0x0..=0x2 => writeln!(
+                f,
+                "Access Address: Index 0x{:04x}, Data 0x{:04x}",
+                self.word(0x10),
+                self.word(0x12)
+            ),
+            0x3 => writeln!(f, "Access Address: 0x{:08x}", self.dword(0x10)),
+            0x4 => writeln!(f, "Access Address: GPNV handle 0x{:04x}", self.word(0x10)),
+            _ => writeln!(f, "Access Address: 0x{:08x}", self.dword(0x10)),
+        }
+    }
+
+    fn fmt_log_status(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let b = self.data.bits[0xb];
+        let bit_strings = [(1, "Log area valid"), (1 << 1, "Log area full")];
+        writeln!(f, "Log Status:")?;
+        decode_byte(f, b, &bit_strings)
+    }
+
+    fn fmt_log_header_format(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let t: &str = match self.log_header_format() {
+            0x0 => "No header",
+            0x1 => "Type 1 log header",
+            0x80..=0xff => "OEM-specific",
+            _ => "Unrecognized header format. Probably a bug.",
+        };
+        writeln!(f, "Log Header Format: {}", t)
+    }
+
+    fn fmt_log_type_descriptors(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = self.data.bits[0x15];
+        let descriptor_len = self.data.bits[0x16];
+        writeln!(f, "Supported Log Type Descriptors: {}", count)?;
+        if descriptor_len < 2 {
+            return Ok(());
+        }
+        for d in self.records(0x17, count.into(), descriptor_len.into()) {
+            writeln!(
+                f,
+                "  + {} (data format: {})",
+                event_type(d[0]),
+                variable_data_format(d[1])
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn fmt_table15(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 15 (System Event Log)")?;
+        let len: u8 = self.size();
+        if len < 0x14 {
+            return Ok(());
+        }
+        writeln!(f, "Log Area Length: {} bytes", self.log_area_length())?;
+        writeln!(f, "Log Header Start Offset: 0x{:04x}", self.log_header_offset())?;
+        writeln!(f, "Log Data Start Offset: 0x{:04x}", self.log_data_offset())?;
+        self.fmt_access_method(f)?;
+        self.fmt_log_status(f)?;
+        writeln!(f, "Log Change Token: 0x{:08x}", self.dword(0xc))?;
+        if len > 0x14 {
+            // Header format and log type descriptors are SMBIOS 2.1+
+            self.fmt_log_header_format(f)?;
+        }
+        if len > 0x16 {
+            self.fmt_log_type_descriptors(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table15() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                15,   // type
+                0x1b, // length, two descriptors
+                0x70, // handle (2 bytes)
+                0x0,
+                0x0, // log area length (2 bytes)
+                0x4,
+                0x0, // log header start offset (2 bytes)
+                0x0,
+                0x10, // log data start offset (2 bytes)
+                0x0,
+                0x3, // access method: memory-mapped
+                0x1, // log status: valid
+                0x4, // log change token (4 bytes)
+                0x3,
+                0x2,
+                0x1,
+                0x0, // access method address (4 bytes)
+                0xf0,
+                0x0f,
+                0x0,
+                0x1, // log header format: type 1
+                2,   // number of log type descriptors
+                2,   // length of each descriptor
+                0x1, // single-bit ECC memory error
+                0x1, // handle
+                0x17, // system boot
+                0x0,  // none
+            ]
+            .to_vec(),
+            strings: [].to_vec(),
+        };
+        let table = Table {
+            id: TableId::SystemEventLog,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Log Area Length: 1024 bytes"));
+        assert!(r.contains("Log Data Start Offset: 0x0010"));
+        assert!(r.contains("Access Method: Memory-mapped physical 32-bit address"));
+        assert!(r.contains("Access Address: 0x000ff000"));
+        assert!(r.contains("  + Log area valid"));
+        assert!(!r.contains("Log area full"));
+        assert!(r.contains("Log Change Token: 0x01020304"));
+        assert!(r.contains("Log Header Format: Type 1 log header"));
+        assert!(r.contains("Supported Log Type Descriptors: 2"));
+        assert!(r.contains("  + Single-bit ECC memory error (data format: Handle)"));
+        assert!(r.contains("  + System boot (data format: None)"));
+        assert_eq!(table.log_area_address(), Some(0xff000));
+    }
+}
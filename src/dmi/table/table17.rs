@@ -0,0 +1,157 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::find_handle;
+use crate::dmi::table::Table;
+use std::fmt;
+
+impl Table {
+    // Handle of the memory error information structure for this
+    // device. 0xfffe means not provided and 0xffff means no error.
+    pub fn memory_error_handle(&self) -> u16 {
+        self.word(0x6)
+    }
+
+    // Size of the memory device in kB. Returns None when the size is
+    // unknown, and Some(0) when no device is installed.
+    pub fn memory_device_size(&self) -> Option<u64> {
+        match self.word(0xc) {
+            0xffff => None,
+            0x7fff if self.size() >= 0x20 => Some(u64::from(self.dword(0x1c) & 0x7fffffff) * 1024),
+            s if s & 0x8000 != 0 => Some(u64::from(s & 0x7fff)),
+            s => Some(u64::from(s) * 1024),
+        }
+    }
+
+    fn fmt_memory_device_size(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.memory_device_size() {
+            None => writeln!(f, "Size: Unknown"),
+            Some(0) => writeln!(f, "Size: No Module Installed"),
+            Some(kb) if kb % 1024 != 0 => writeln!(f, "Size: {} kB", kb),
+            Some(kb) => writeln!(f, "Size: {} MB", kb / 1024),
+        }
+    }
+
+    fn fmt_memory_error_handle(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.memory_error_handle() {
+            0xfffe => writeln!(f, "Memory Error Information Handle: Not Provided"),
+            0xffff => writeln!(f, "Memory Error Information Handle: No Error"),
+            h => writeln!(f, "Memory Error Information Handle: 0x{:04x}", h),
+        }
+    }
+
+    pub fn fmt_table17(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 17 (Memory Device)")?;
+        let len: u8 = self.size();
+        if len < 0x15 {
+            return Ok(());
+        }
+        writeln!(f, "Physical Memory Array Handle: 0x{:04x}", self.word(0x4))?;
+        self.fmt_memory_error_handle(f)?;
+        self.fmt_memory_device_size(f)?;
+        self.fmt_str(f, 0x10, "Device Locator")?;
+        self.fmt_str(f, 0x11, "Bank Locator")?;
+        if len >= 0x1b {
+            self.fmt_str(f, 0x17, "Manufacturer")?;
+            self.fmt_str(f, 0x18, "Serial Number")?;
+            self.fmt_str(f, 0x1a, "Part Number")?;
+        }
+        Ok(())
+    }
+
+    pub fn fmt_table17_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        if self.size() < 0x15 {
+            return Ok(());
+        }
+        let handle = self.memory_error_handle();
+        if handle >= 0xfffe {
+            return Ok(());
+        }
+        match find_handle(tables, handle) {
+            Some(e) if e.memory_error_recorded() => writeln!(
+                f,
+                "Memory Error: {} (see handle 0x{:04x})",
+                e.memory_error_type(),
+                handle
+            ),
+            Some(_) => writeln!(f, "Memory Error: None recorded"),
+            None => writeln!(f, "Memory Error: Handle 0x{:04x} not found", handle),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn memory_device(handle: u8, error_handle: u16, size: u16) -> Table {
+        let mut bits = vec![0; 0x28];
+        bits[0] = 17; // type
+        bits[1] = 0x28; // length
+        bits[2] = handle; // handle
+        bits[0x4] = 0x30; // physical memory array handle
+        bits[0x6..0x8].clone_from_slice(&error_handle.to_le_bytes());
+        bits[0xc..0xe].clone_from_slice(&size.to_le_bytes());
+        bits[0x10] = 1; // device locator string
+        bits[0x11] = 2; // bank locator string
+        bits[0x17] = 3; // manufacturer string
+        bits[0x18] = 4; // serial number string
+        bits[0x1a] = 5; // part number string
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits,
+            strings: [
+                String::from("DIMM A1"),
+                String::from("P0 CHANNEL A"),
+                String::from("Samsung"),
+                String::from("0123ABCD"),
+                String::from("M393A2K43BB1-CTD"),
+            ]
+            .to_vec(),
+        };
+        Table {
+            id: TableId::MemoryDevice,
+            data: d,
+        }
+    }
+
+    #[test]
+    fn test_decode_table17() {
+        let r = format!("{}", memory_device(0x50, 0xfffe, 0x4000));
+        println!("{}", r);
+        assert!(r.contains("Physical Memory Array Handle: 0x0030"));
+        assert!(r.contains("Memory Error Information Handle: Not Provided"));
+        assert!(r.contains("Size: 16384 MB"));
+        assert!(r.contains("Device Locator: DIMM A1"));
+        assert!(r.contains("Bank Locator: P0 CHANNEL A"));
+        assert!(r.contains("Part Number: M393A2K43BB1-CTD"));
+    }
+
+    #[test]
+    fn test_decode_table17_sizes() {
+        let r = format!("{}", memory_device(0x50, 0xffff, 0));
+        assert!(r.contains("Memory Error Information Handle: No Error"));
+        assert!(r.contains("Size: No Module Installed"));
+        let r = format!("{}", memory_device(0x50, 0xffff, 0x8200));
+        assert!(r.contains("Size: 512 kB"));
+        let r = format!("{}", memory_device(0x50, 0xffff, 0xffff));
+        assert!(r.contains("Size: Unknown"));
+    }
+}
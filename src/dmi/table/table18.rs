@@ -0,0 +1,230 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use std::fmt;
+
+// Section 7.19 of SMBIOS spec 3.5.0. These enumerations are shared
+// with the 64-bit structure (Type 33).
+pub fn memory_error_type(b: u8) -> &'static str {
+    match b {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "OK",
+        0x4 => "Bad read",
+        0x5 => "Parity error",
+        0x6 => "Single-bit error",
+        0x7 => "Double-bit error",
+        0x8 => "Multi-bit error",
+        0x9 => "Nibble error",
+        0xa => "Checksum error",
+        0xb => "CRC error",
+        0xc => "Corrected single-bit error",
+        0xd => "Corrected error",
+        0xe => "Uncorrectable error",
+        _ => "Unrecognized error type. Probably a bug.",
+    }
+}
+
+pub fn memory_error_granularity(b: u8) -> &'static str {
+    match b {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "Device level",
+        0x4 => "Memory partition level",
+        _ => "Unrecognized granularity. Probably a bug.",
+    }
+}
+
+pub fn memory_error_operation(b: u8) -> &'static str {
+    match b {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "Read",
+        0x4 => "Write",
+        0x5 => "Partial write",
+        _ => "Unrecognized operation. Probably a bug.",
+    }
+}
+
+impl Table {
+    pub fn memory_error_type(&self) -> &'static str {
+        memory_error_type(self.data.bits[0x4])
+    }
+
+    // True if this error information structure describes an actual
+    // error, rather than OK or Unknown
+    pub fn memory_error_recorded(&self) -> bool {
+        (self.id() == 18 || self.id() == 33)
+            && self.size() > 0x4
+            && !matches!(self.data.bits[0x4], 0x2 | 0x3)
+    }
+
+    // Fields common to the 32-bit and 64-bit structures
+    pub fn fmt_memory_error_common(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Error Type: {}", self.memory_error_type())?;
+        writeln!(
+            f,
+            "Error Granularity: {}",
+            memory_error_granularity(self.data.bits[0x5])
+        )?;
+        writeln!(
+            f,
+            "Error Operation: {}",
+            memory_error_operation(self.data.bits[0x6])
+        )?;
+        match self.dword(0x7) {
+            0 => writeln!(f, "Vendor Syndrome: Unknown"),
+            s => writeln!(f, "Vendor Syndrome: 0x{:08x}", s),
+        }
+    }
+
+    fn fmt_error_address32(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        offset: usize,
+        label: &str,
+    ) -> fmt::Result {
+        match self.dword(offset) {
+            0x80000000 => writeln!(f, "{}: Unknown", label),
+            a => writeln!(f, "{}: 0x{:08x}", label, a),
+        }
+    }
+
+    pub fn fmt_error_resolution(&self, f: &mut fmt::Formatter<'_>, offset: usize) -> fmt::Result {
+        match self.dword(offset) {
+            0x80000000 => writeln!(f, "Error Resolution: Unknown"),
+            r => writeln!(f, "Error Resolution: {} bytes", r),
+        }
+    }
+
+    pub fn fmt_table18(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 18 (32-Bit Memory Error Information)")?;
+        if self.size() < 0x17 {
+            return Ok(());
+        }
+        self.fmt_memory_error_common(f)?;
+        self.fmt_error_address32(f, 0xb, "Memory Array Error Address")?;
+        self.fmt_error_address32(f, 0xf, "Device Error Address")?;
+        self.fmt_error_resolution(f, 0x13)
+    }
+
+    // Lists the structures that point at this one through their
+    // memory error information handle
+    pub fn fmt_memory_error_links(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        tables: &[Table],
+    ) -> fmt::Result {
+        let referrers: Vec<&Table> = tables
+            .iter()
+            .filter(|t| match t.id() {
+                16 => t.size() >= 0xf && t.word(0xb) == self.handle(),
+                17 => t.size() >= 0x15 && t.word(0x6) == self.handle(),
+                _ => false,
+            })
+            .collect();
+        if referrers.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "Referenced by:")?;
+        for t in referrers {
+            writeln!(f, "  + {}", t.summary())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn error_table() -> Table {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                18,   // type
+                0x17, // length
+                0x60, // handle (2 bytes)
+                0x0,
+                0x6, // error type: single-bit
+                0x3, // granularity: device level
+                0x3, // operation: read
+                0x0, 0x0, 0x0, 0x0, // vendor syndrome
+                0x0, 0x10, 0x0, 0x0, // memory array error address
+                0x0, 0x0, 0x0, 0x80, // device error address: unknown
+                0x40, 0x0, 0x0, 0x0, // error resolution
+            ]
+            .to_vec(),
+            strings: [].to_vec(),
+        };
+        Table {
+            id: TableId::MemoryError32,
+            data: d,
+        }
+    }
+
+    fn dimm() -> Table {
+        let mut bits = vec![0; 0x15];
+        bits[0] = 17; // type
+        bits[1] = 0x15; // length
+        bits[2] = 0x50; // handle
+        bits[0x6] = 0x60; // memory error information handle
+        bits[0xd] = 0x40; // size: 16 GB
+        bits[0x10] = 1; // device locator string
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits,
+            strings: [String::from("DIMM B2")].to_vec(),
+        };
+        Table {
+            id: TableId::MemoryDevice,
+            data: d,
+        }
+    }
+
+    #[test]
+    fn test_decode_table18() {
+        let r = format!("{}", error_table());
+        println!("{}", r);
+        assert!(r.contains("Error Type: Single-bit error"));
+        assert!(r.contains("Error Granularity: Device level"));
+        assert!(r.contains("Error Operation: Read"));
+        assert!(r.contains("Vendor Syndrome: Unknown"));
+        assert!(r.contains("Memory Array Error Address: 0x00001000"));
+        assert!(r.contains("Device Error Address: Unknown"));
+        assert!(r.contains("Error Resolution: 64 bytes"));
+    }
+
+    #[test]
+    fn test_memory_error_resolved_from_table17() {
+        let tables = [dimm(), error_table()];
+        let r = format!("{}", tables[0].linked(&tables));
+        println!("{}", r);
+        assert!(r.contains("Memory Error Information Handle: 0x0060"));
+        assert!(r.contains("Memory Error: Single-bit error (see handle 0x0060)"));
+
+        let r = format!("{}", tables[1].linked(&tables));
+        println!("{}", r);
+        assert!(r.contains("Referenced by:\n  + Handle 0x0050, Type 17 (Memory Device): DIMM B2"));
+    }
+}
@@ -18,6 +18,19 @@ use crate::dmi::table::decode_byte;
 use crate::dmi::table::Table;
 use std::fmt;
 
+// Output line prefix for each field offset, used to show Type 40
+// entries next to the field they refer to
+pub const TABLE2_FIELDS: &[(u8, &str)] = &[
+    (0x4, "Manufacturer:"),
+    (0x5, "Product:"),
+    (0x6, "Version:"),
+    (0x7, "Serial:"),
+    (0x8, "Asset tag:"),
+    (0x9, "Baseboard features:"),
+    (0xa, "Location in chassis:"),
+    (0xd, "Board type:"),
+];
+
 impl Table {
     pub fn fmt_baseboard_manufacturer(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.fmt_str(f, 4, "Manufacturer")
@@ -0,0 +1,100 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use std::fmt;
+
+fn pointing_device_type(b: u8) -> &'static str {
+    match b {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "Mouse",
+        0x4 => "Track Ball",
+        0x5 => "Track Point",
+        0x6 => "Glide Point",
+        0x7 => "Touch Pad",
+        0x8 => "Touch Screen",
+        0x9 => "Optical Sensor",
+        _ => "Unrecognized device type. Probably a bug.",
+    }
+}
+
+fn pointing_device_interface(b: u8) -> &'static str {
+    match b {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "Serial",
+        0x4 => "PS/2",
+        0x5 => "Infrared",
+        0x6 => "HP-HIL",
+        0x7 => "Bus mouse",
+        0x8 => "ADB (Apple Desktop Bus)",
+        0xa0 => "Bus mouse DB-9",
+        0xa1 => "Bus mouse micro DIN",
+        0xa2 => "USB",
+        0xa3 => "I2C",
+        0xa4 => "SPI",
+        _ => "Unrecognized interface. Probably a bug.",
+    }
+}
+
+impl Table {
+    pub fn fmt_table21(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 21 (Built-in Pointing Device)")?;
+        if self.size() < 0x7 {
+            return Ok(());
+        }
+        writeln!(f, "Type: {}", pointing_device_type(self.data.bits[0x4]))?;
+        writeln!(f, "Interface: {}", pointing_device_interface(self.data.bits[0x5]))?;
+        writeln!(f, "Buttons: {}", self.data.bits[0x6])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    #[test]
+    fn test_decode_table21() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                21,   // type
+                0x7,  // length
+                0x0,  // handle (2 bytes)
+                0x15,
+                0x7,  // touch pad
+                0xa3, // I2C
+                2,    // buttons
+            ]
+            .to_vec(),
+            strings: [].to_vec(),
+        };
+        let table = Table {
+            id: TableId::PointingDevice,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Type: Touch Pad"));
+        assert!(r.contains("Interface: I2C"));
+        assert!(r.contains("Buttons: 2"));
+    }
+}
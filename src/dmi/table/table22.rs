@@ -0,0 +1,187 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use std::fmt;
+
+fn battery_chemistry(b: u8) -> &'static str {
+    match b {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "Lead Acid",
+        0x4 => "Nickel Cadmium",
+        0x5 => "Nickel metal hydride",
+        0x6 => "Lithium-ion",
+        0x7 => "Zinc air",
+        0x8 => "Lithium Polymer",
+        _ => "Unrecognized chemistry. Probably a bug.",
+    }
+}
+
+impl Table {
+    // The string fields for manufacture date, serial number and
+    // chemistry are superseded by their SBDS (Smart Battery Data
+    // Specification) forms when the string number is zero or the
+    // chemistry is "Unknown"
+    fn fmt_battery_date(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.data.bits[0x6] != 0 || self.size() < 0x14 {
+            return self.fmt_str(f, 0x6, "Manufacture Date");
+        }
+        // bits 15:9 are the year biased by 1980, 8:5 the month and
+        // 4:0 the day
+        let d = self.word(0x12);
+        writeln!(
+            f,
+            "Manufacture Date: {}-{:02}-{:02}",
+            1980 + (d >> 9),
+            (d >> 5) & 0xf,
+            d & 0x1f
+        )
+    }
+
+    fn fmt_battery_serial(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.data.bits[0x7] != 0 || self.size() < 0x12 {
+            return self.fmt_str(f, 0x7, "Serial Number");
+        }
+        writeln!(f, "Serial Number: 0x{:04x}", self.word(0x10))
+    }
+
+    fn fmt_battery_chemistry(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let b = self.data.bits[0x9];
+        if b == 0x2 && self.size() >= 0x15 && self.data.bits[0x14] != 0 {
+            return self.fmt_str(f, 0x14, "Chemistry");
+        }
+        writeln!(f, "Chemistry: {}", battery_chemistry(b))
+    }
+
+    fn fmt_battery_capacity(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let multiplier: u32 = match self.size() {
+            0..=0x15 => 1,
+            _ => self.data.bits[0x15].max(1).into(),
+        };
+        match self.word(0xa) {
+            0 => writeln!(f, "Design Capacity: Unknown"),
+            c => writeln!(f, "Design Capacity: {} mWh", u32::from(c) * multiplier),
+        }
+    }
+
+    fn fmt_battery_voltage(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.word(0xc) {
+            0 => writeln!(f, "Design Voltage: Unknown"),
+            v => writeln!(f, "Design Voltage: {} mV", v),
+        }
+    }
+
+    pub fn fmt_table22(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 22 (Portable Battery)")?;
+        let len: u8 = self.size();
+        if len < 0x10 {
+            return Ok(());
+        }
+        self.fmt_str(f, 0x4, "Location")?;
+        self.fmt_str(f, 0x5, "Manufacturer")?;
+        self.fmt_battery_date(f)?;
+        self.fmt_battery_serial(f)?;
+        self.fmt_str(f, 0x8, "Name")?;
+        self.fmt_battery_chemistry(f)?;
+        self.fmt_battery_capacity(f)?;
+        self.fmt_battery_voltage(f)?;
+        self.fmt_str(f, 0xe, "SBDS Version")?;
+        match self.data.bits[0xf] {
+            0xff => writeln!(f, "Maximum Error: Unknown")?,
+            e => writeln!(f, "Maximum Error: {}%", e)?,
+        }
+        if len >= 0x1a {
+            writeln!(f, "OEM-specific Information: 0x{:08x}", self.dword(0x16))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn battery(date: u8, serial: u8, chemistry: u8) -> Table {
+        let mut bits = vec![0; 0x1a];
+        bits[0] = 22; // type
+        bits[1] = 0x1a; // length
+        bits[2] = 0x70; // handle
+        bits[0x4] = 1; // location string
+        bits[0x5] = 2; // manufacturer string
+        bits[0x6] = date; // manufacture date string
+        bits[0x7] = serial; // serial number string
+        bits[0x8] = 3; // device name string
+        bits[0x9] = chemistry;
+        bits[0xa..0xc].clone_from_slice(&5700u16.to_le_bytes()); // design capacity
+        bits[0xc..0xe].clone_from_slice(&11400u16.to_le_bytes()); // design voltage
+        bits[0xe] = 4; // SBDS version string
+        bits[0xf] = 1; // maximum error
+        bits[0x10..0x12].clone_from_slice(&0x1234u16.to_le_bytes()); // SBDS serial
+        // SBDS date: 2021-03-14
+        let sbds_date: u16 = (41 << 9) | (3 << 5) | 14;
+        bits[0x12..0x14].clone_from_slice(&sbds_date.to_le_bytes());
+        bits[0x14] = 5; // SBDS chemistry string
+        bits[0x15] = 10; // design capacity multiplier
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits,
+            strings: [
+                String::from("Front"),
+                String::from("SMP"),
+                String::from("5B10W13975"),
+                String::from("03.01"),
+                String::from("LiP"),
+                String::from("2020/01/01"),
+                String::from("S/N 42"),
+            ]
+            .to_vec(),
+        };
+        Table {
+            id: TableId::PortableBattery,
+            data: d,
+        }
+    }
+
+    #[test]
+    fn test_decode_table22_sbds() {
+        let r = format!("{}", battery(0, 0, 0x2));
+        println!("{}", r);
+        assert!(r.contains("Location: Front"));
+        assert!(r.contains("Manufacturer: SMP"));
+        assert!(r.contains("Manufacture Date: 2021-03-14"));
+        assert!(r.contains("Serial Number: 0x1234"));
+        assert!(r.contains("Name: 5B10W13975"));
+        assert!(r.contains("Chemistry: LiP"));
+        assert!(r.contains("Design Capacity: 57000 mWh"));
+        assert!(r.contains("Design Voltage: 11400 mV"));
+        assert!(r.contains("SBDS Version: 03.01"));
+        assert!(r.contains("Maximum Error: 1%"));
+    }
+
+    #[test]
+    fn test_decode_table22_strings() {
+        let r = format!("{}", battery(6, 7, 0x6));
+        println!("{}", r);
+        assert!(r.contains("Manufacture Date: 2020/01/01"));
+        assert!(r.contains("Serial Number: S/N 42"));
+        assert!(r.contains("Chemistry: Lithium-ion"));
+    }
+}
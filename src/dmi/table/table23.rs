@@ -0,0 +1,113 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use std::fmt;
+
+fn boot_option(b: u8) -> &'static str {
+    match b & 3 {
+        0x1 => "Operating System",
+        0x2 => "System Utilities",
+        0x3 => "Do Not Reboot",
+        _ => "Reserved",
+    }
+}
+
+impl Table {
+    // 0xffff means the value is unknown
+    fn fmt_reset_word(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        offset: usize,
+        label: &str,
+        unit: &str,
+    ) -> fmt::Result {
+        match self.word(offset) {
+            0xffff => writeln!(f, "{}: Unknown", label),
+            v => writeln!(f, "{}: {}{}", label, v, unit),
+        }
+    }
+
+    pub fn fmt_table23(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 23 (System Reset)")?;
+        if self.size() < 0xd {
+            return Ok(());
+        }
+        let b = self.data.bits[0x4];
+        if b & 1 != 0 {
+            writeln!(f, "Status: Enabled")?;
+        } else {
+            writeln!(f, "Status: Disabled")?;
+        }
+        if b & (1 << 5) != 0 {
+            writeln!(f, "Watchdog Timer: Present")?;
+        } else {
+            writeln!(f, "Watchdog Timer: Not Present")?;
+        }
+        writeln!(f, "Boot Option: {}", boot_option(b >> 1))?;
+        writeln!(f, "Boot Option On Limit: {}", boot_option(b >> 3))?;
+        self.fmt_reset_word(f, 0x5, "Reset Count", "")?;
+        self.fmt_reset_word(f, 0x7, "Reset Limit", "")?;
+        self.fmt_reset_word(f, 0x9, "Timer Interval", " min")?;
+        self.fmt_reset_word(f, 0xb, "Timeout", " min")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table23() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                23,   // type
+                0xd,  // length
+                0x80, // handle (2 bytes)
+                0x0,
+                1 | (1 << 1) | (3 << 3) | (1 << 5), // capabilities
+                0x2, // reset count (2 bytes)
+                0x0,
+                0xff, // reset limit: unknown (2 bytes)
+                0xff,
+                0x5, // timer interval (2 bytes)
+                0x0,
+                0xff, // timeout: unknown (2 bytes)
+                0xff,
+            ]
+            .to_vec(),
+            strings: [].to_vec(),
+        };
+        let table = Table {
+            id: TableId::SystemReset,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Status: Enabled"));
+        assert!(r.contains("Watchdog Timer: Present"));
+        assert!(r.contains("Boot Option: Operating System"));
+        assert!(r.contains("Boot Option On Limit: Do Not Reboot"));
+        assert!(r.contains("Reset Count: 2"));
+        assert!(r.contains("Reset Limit: Unknown"));
+        assert!(r.contains("Timer Interval: 5 min"));
+        assert!(r.contains("Timeout: Unknown"));
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::decode_fields;
+use crate::dmi::table::Table;
+use std::fmt;
+
+impl Table {
+    pub fn fmt_table24(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 24 (Hardware Security)")?;
+        if self.size() < 0x5 {
+            return Ok(());
+        }
+        let fields = [
+            (6, 2, "Power-On Password Status"),
+            (4, 2, "Keyboard Password Status"),
+            (2, 2, "Administrator Password Status"),
+            (0, 2, "Front Panel Reset Status"),
+        ];
+        let value_strings = ["Disabled", "Enabled", "Not Implemented", "Unknown"];
+        decode_fields(f, self.data.bits[0x4], &fields, &value_strings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table24() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                24,   // type
+                0x5,  // length
+                0x90, // handle (2 bytes)
+                0x0,
+                0b01_00_10_11, // settings
+            ]
+            .to_vec(),
+            strings: [].to_vec(),
+        };
+        let table = Table {
+            id: TableId::HardwareSecurity,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Power-On Password Status: Enabled"));
+        assert!(r.contains("Keyboard Password Status: Disabled"));
+        assert!(r.contains("Administrator Password Status: Not Implemented"));
+        assert!(r.contains("Front Panel Reset Status: Unknown"));
+    }
+}
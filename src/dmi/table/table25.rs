@@ -0,0 +1,87 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::decode_bcd;
+use crate::dmi::table::Table;
+use std::fmt;
+
+impl Table {
+    // Fields are BCD. Anything that isn't valid BCD within the
+    // field's range (normally 0xff) is treated as unknown.
+    fn fmt_power_on_field(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        offset: usize,
+        label: &str,
+        range: std::ops::RangeInclusive<u8>,
+    ) -> fmt::Result {
+        match decode_bcd(self.data.bits[offset]) {
+            Some(v) if range.contains(&v) => writeln!(f, "{}: {:02}", label, v),
+            _ => writeln!(f, "{}: Unknown", label),
+        }
+    }
+
+    pub fn fmt_table25(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 25 (System Power Controls)")?;
+        if self.size() < 0x9 {
+            return Ok(());
+        }
+        self.fmt_power_on_field(f, 0x4, "Next Scheduled Power-on Month", 1..=12)?;
+        self.fmt_power_on_field(f, 0x5, "Next Scheduled Power-on Day", 1..=31)?;
+        self.fmt_power_on_field(f, 0x6, "Next Scheduled Power-on Hour", 0..=23)?;
+        self.fmt_power_on_field(f, 0x7, "Next Scheduled Power-on Minute", 0..=59)?;
+        self.fmt_power_on_field(f, 0x8, "Next Scheduled Power-on Second", 0..=59)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table25() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                25,   // type
+                0x9,  // length
+                0x81, // handle (2 bytes)
+                0x0,
+                0x12, // month
+                0x31, // day
+                0xff, // hour: unknown
+                0x30, // minute
+                0x75, // second: out of range
+            ]
+            .to_vec(),
+            strings: [].to_vec(),
+        };
+        let table = Table {
+            id: TableId::PowerControls,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Next Scheduled Power-on Month: 12"));
+        assert!(r.contains("Next Scheduled Power-on Day: 31"));
+        assert!(r.contains("Next Scheduled Power-on Hour: Unknown"));
+        assert!(r.contains("Next Scheduled Power-on Minute: 30"));
+        assert!(r.contains("Next Scheduled Power-on Second: Unknown"));
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::probe::ProbeUnits;
+use crate::dmi::table::Table;
+use std::fmt;
+
+// Values are in millivolts and resolution in tenths of millivolts
+pub const VOLTAGE: ProbeUnits = ProbeUnits {
+    value_divisor: 1000.0,
+    value_precision: 3,
+    value_unit: "V",
+    resolution_divisor: 10.0,
+    resolution_precision: 1,
+    resolution_unit: "mV",
+};
+
+impl Table {
+    pub fn fmt_table26(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 26 (Voltage Probe)")?;
+        self.fmt_probe(f, &VOLTAGE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::table;
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table26() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                26,   // type
+                0x16, // length
+                0xa0, // handle (2 bytes)
+                0x0,
+                1,              // description string
+                (3 << 5) | 0x3, // status OK, location processor
+                0xdc, 0x05, // maximum: 1500 mV
+                0xb0, 0x04, // minimum: 1200 mV
+                0x0a, 0x00, // resolution: 1.0 mV
+                0x32, 0x00, // tolerance: 50 mV
+                0x32, 0x00, // accuracy: 0.50%
+                0x0, 0x0, 0x0, 0x0, // OEM-defined
+                0x00, 0x80, // nominal value: unknown
+            ]
+            .to_vec(),
+            strings: [String::from("CPU Core Voltage")].to_vec(),
+        };
+        let table = Table {
+            id: TableId::VoltageProbe,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Description: CPU Core Voltage"));
+        assert!(r.contains("Location: Processor"));
+        assert!(r.contains("Status: OK"));
+        assert!(r.contains("Maximum Value: 1.500 V"));
+        assert!(r.contains("Minimum Value: 1.200 V"));
+        assert!(r.contains("Resolution: 1.0 mV"));
+        assert!(r.contains("Tolerance: +/- 0.050 V"));
+        assert!(r.contains("Accuracy: +/- 0.50%"));
+        assert!(r.contains("Nominal Value: Unknown"));
+    }
+
+    #[test]
+    fn test_decode_table26_unsigned_fields() {
+        let table = table(
+            TableId::VoltageProbe,
+            vec![
+                26,   // type
+                0x14, // length
+                0xa1, // handle (2 bytes)
+                0x0,
+                0,              // description string
+                (3 << 5) | 0xc, // status OK, location front panel board
+                0x00, 0x80, // maximum: unknown
+                0x00, 0x80, // minimum: unknown
+                0x10, 0x90, // resolution: 3688.0 mV
+                0x00, 0x80, // tolerance: unknown
+                0x50, 0xc3, // accuracy: 500.00%
+                0x0, 0x0, 0x0, 0x0, // OEM-defined
+            ],
+            vec![],
+        );
+        let r = format!("{}", table);
+        println!("{}", r);
+        // Front Panel Board is only defined for temperature probes
+        assert!(r.contains("Location: Unrecognized location. Probably a bug."));
+        assert!(r.contains("Resolution: 3688.0 mV"));
+        assert!(r.contains("Accuracy: +/- 500.00%"));
+    }
+}
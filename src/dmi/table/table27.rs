@@ -0,0 +1,180 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::find_handle;
+use crate::dmi::table::probe::probe_status;
+use crate::dmi::table::probe::ProbeUnits;
+use crate::dmi::table::Table;
+use std::fmt;
+
+// Thresholds for cooling devices are in rpm, like the nominal speed
+pub const SPEED: ProbeUnits = ProbeUnits {
+    value_divisor: 1.0,
+    value_precision: 0,
+    value_unit: "rpm",
+    resolution_divisor: 1.0,
+    resolution_precision: 0,
+    resolution_unit: "rpm",
+};
+
+fn cooling_device_type(b: u8) -> &'static str {
+    match b & 0x1f {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "Fan",
+        0x4 => "Centrifugal Blower",
+        0x5 => "Chip Fan",
+        0x6 => "Cabinet Fan",
+        0x7 => "Power Supply Fan",
+        0x8 => "Heat Pipe",
+        0x9 => "Integrated Refrigeration",
+        0x10 => "Active Cooling",
+        0x11 => "Passive Cooling",
+        _ => "Unrecognized device type. Probably a bug.",
+    }
+}
+
+impl Table {
+    fn temperature_probe_handle(&self) -> u16 {
+        self.word(0x4)
+    }
+
+    // Zero means the device isn't part of a redundant cooling unit
+    fn cooling_unit_group(&self) -> u8 {
+        self.data.bits[0x7]
+    }
+
+    pub fn fmt_table27(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 27 (Cooling Device)")?;
+        let len = self.size();
+        if len < 0xc {
+            return Ok(());
+        }
+        match self.temperature_probe_handle() {
+            0xffff => writeln!(f, "Temperature Probe Handle: None")?,
+            h => writeln!(f, "Temperature Probe Handle: 0x{:04x}", h)?,
+        }
+        let b = self.data.bits[0x6];
+        writeln!(f, "Type: {}", cooling_device_type(b))?;
+        writeln!(f, "Status: {}", probe_status(b))?;
+        match self.cooling_unit_group() {
+            0 => writeln!(f, "Cooling Unit Group: None")?,
+            g => writeln!(f, "Cooling Unit Group: {}", g)?,
+        }
+        writeln!(f, "OEM-specific Information: 0x{:08x}", self.dword(0x8))?;
+        if len >= 0xe {
+            match self.word(0xc) {
+                0x8000 => writeln!(f, "Nominal Speed: Unknown Or Non-rotating")?,
+                s => writeln!(f, "Nominal Speed: {} rpm", s)?,
+            }
+        }
+        if len >= 0xf {
+            self.fmt_str(f, 0xe, "Description")?;
+        }
+        Ok(())
+    }
+
+    pub fn fmt_table27_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        if self.size() < 0xc {
+            return Ok(());
+        }
+        let handle = self.temperature_probe_handle();
+        if handle != 0xffff {
+            match find_handle(tables, handle) {
+                Some(t) => writeln!(f, "Temperature Probe: {}", t.summary())?,
+                None => writeln!(f, "Temperature Probe: Handle 0x{:04x} not found", handle)?,
+            }
+        }
+        let group = self.cooling_unit_group();
+        if group == 0 {
+            return Ok(());
+        }
+        writeln!(f, "Cooling Unit Group {} members:", group)?;
+        for t in tables
+            .iter()
+            .filter(|t| t.id() == 27 && t.size() >= 0xc && t.cooling_unit_group() == group)
+        {
+            writeln!(f, "  + {}", t.summary())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::table;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn fan(handle: u8, group: u8, name: &str) -> Table {
+        table(
+            TableId::CoolingDevice,
+            [
+                27,     // type
+                0xf,    // length
+                handle, // handle (2 bytes)
+                0x0,
+                0xa1, // temperature probe handle (2 bytes)
+                0x0,
+                (3 << 5) | 0x3, // status OK, fan
+                group,          // cooling unit group
+                0x0,            // OEM-defined (4 bytes)
+                0x0,
+                0x0,
+                0x0,
+                0xb8, // nominal speed: 3000 rpm (2 bytes)
+                0x0b,
+                1, // description string
+            ]
+            .to_vec(),
+            [String::from(name)].to_vec(),
+        )
+    }
+
+    #[test]
+    fn test_decode_table27() {
+        let r = format!("{}", fan(0xb0, 1, "Fan 1"));
+        println!("{}", r);
+        assert!(r.contains("Temperature Probe Handle: 0x00a1"));
+        assert!(r.contains("Type: Fan"));
+        assert!(r.contains("Status: OK"));
+        assert!(r.contains("Cooling Unit Group: 1"));
+        assert!(r.contains("Nominal Speed: 3000 rpm"));
+        assert!(r.contains("Description: Fan 1"));
+    }
+
+    #[test]
+    fn test_decode_table27_linked() {
+        let mut probe = vec![0; 0x14];
+        probe[0] = 28;
+        probe[1] = 0x14;
+        probe[2] = 0xa1;
+        probe[4] = 1;
+        let tables = [
+            table(TableId::TemperatureProbe, probe, [String::from("CPU Temp")].to_vec()),
+            fan(0xb0, 1, "Fan 1"),
+            fan(0xb1, 1, "Fan 2"),
+            fan(0xb2, 2, "Fan 3"),
+        ];
+        let r = format!("{}", tables[1].linked(&tables));
+        println!("{}", r);
+        assert!(r.contains("Temperature Probe: Handle 0x00a1, Type 28 (Temperature Probe): CPU Temp"));
+        assert!(r.contains("Cooling Unit Group 1 members:"));
+        assert!(r.contains("  + Handle 0x00b0, Type 27 (Cooling Device): Fan 1"));
+        assert!(r.contains("  + Handle 0x00b1, Type 27 (Cooling Device): Fan 2"));
+        assert!(!r.contains("Fan 3"));
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::probe::ProbeUnits;
+use crate::dmi::table::Table;
+use std::fmt;
+
+// Values are in 1/10th degrees C and resolution in 1/1000th degrees C
+pub const TEMPERATURE: ProbeUnits = ProbeUnits {
+    value_divisor: 10.0,
+    value_precision: 1,
+    value_unit: "°C",
+    resolution_divisor: 1000.0,
+    resolution_precision: 3,
+    resolution_unit: "°C",
+};
+
+impl Table {
+    pub fn fmt_table28(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 28 (Temperature Probe)")?;
+        self.fmt_probe(f, &TEMPERATURE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table28() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                28,   // type
+                0x16, // length
+                0xa1, // handle (2 bytes)
+                0x0,
+                1,              // description string
+                (5 << 5) | 0xc, // status critical, location front panel
+                0x52, 0x03, // maximum: 85.0 C
+                0xe7, 0xff, // minimum: -2.5 C
+                0xf4, 0x01, // resolution: 0.5 C
+                0x00, 0x80, // tolerance: unknown
+                0x00, 0x80, // accuracy: unknown
+                0x0, 0x0, 0x0, 0x0, // OEM-defined
+                0xfa, 0x00, // nominal value: 25.0 C
+            ]
+            .to_vec(),
+            strings: [String::from("Front Panel Temp")].to_vec(),
+        };
+        let table = Table {
+            id: TableId::TemperatureProbe,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Location: Front Panel Board"));
+        assert!(r.contains("Status: Critical"));
+        assert!(r.contains("Maximum Value: 85.0 °C"));
+        assert!(r.contains("Minimum Value: -2.5 °C"));
+        assert!(r.contains("Resolution: 0.500 °C"));
+        assert!(r.contains("Tolerance: Unknown"));
+        assert!(r.contains("Accuracy: Unknown"));
+        assert!(r.contains("Nominal Value: 25.0 °C"));
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::probe::ProbeUnits;
+use crate::dmi::table::Table;
+use std::fmt;
+
+// Values are in milliamps and resolution in tenths of milliamps
+pub const CURRENT: ProbeUnits = ProbeUnits {
+    value_divisor: 1000.0,
+    value_precision: 3,
+    value_unit: "A",
+    resolution_divisor: 10.0,
+    resolution_precision: 1,
+    resolution_unit: "mA",
+};
+
+impl Table {
+    pub fn fmt_table29(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 29 (Electrical Current Probe)")?;
+        self.fmt_probe(f, &CURRENT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table29() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                29,   // type
+                0x14, // length, no nominal value
+                0xa2, // handle (2 bytes)
+                0x0,
+                1,              // description string
+                (3 << 5) | 0xa, // status OK, location power unit
+                0x88, 0x13, // maximum: 5000 mA
+                0x00, 0x00, // minimum: 0 mA
+                0x05, 0x00, // resolution: 0.5 mA
+                0x64, 0x00, // tolerance: 100 mA
+                0x64, 0x00, // accuracy: 1.00%
+                0x78, 0x56, 0x34, 0x12, // OEM-defined
+            ]
+            .to_vec(),
+            strings: [String::from("PSU1 Current")].to_vec(),
+        };
+        let table = Table {
+            id: TableId::CurrentProbe,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Description: PSU1 Current"));
+        assert!(r.contains("Location: Power Unit"));
+        assert!(r.contains("Maximum Value: 5.000 A"));
+        assert!(r.contains("Minimum Value: 0.000 A"));
+        assert!(r.contains("Resolution: 0.5 mA"));
+        assert!(r.contains("Tolerance: +/- 0.100 A"));
+        assert!(r.contains("Accuracy: +/- 1.00%"));
+        assert!(r.contains("OEM-specific Information: 0x12345678"));
+        assert!(!r.contains("Nominal Value"));
+    }
+}
@@ -17,6 +17,24 @@
 use crate::dmi::table::Table;
 use std::fmt;
 
+// Output line prefix for each field offset, used to show Type 40
+// entries next to the field they refer to. The SKU follows the
+// contained elements, so its offset isn't fixed.
+pub const TABLE3_FIELDS: &[(u8, &str)] = &[
+    (0x4, "Manufacturer:"),
+    (0x5, "System Enclosure or Chassis Type:"),
+    (0x6, "Version:"),
+    (0x7, "Serial Number:"),
+    (0x8, "Asset Tag:"),
+    (0x9, "System Enclosure or Chassis State:"),
+    (0xa, "Power supply State:"),
+    (0xb, "Thermal State:"),
+    (0xc, "Chassis security status:"),
+    (0x11, "Chassis rack height:"),
+    (0x12, "Number of power cords:"),
+    (0x13, "Contained elements:"),
+];
+
 impl Table {
     pub fn fmt_chassis_manufacturer(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.fmt_str(f, 4, "Manufacturer")
@@ -0,0 +1,73 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use std::fmt;
+
+impl Table {
+    pub fn fmt_table30(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 30 (Out-of-Band Remote Access)")?;
+        if self.size() < 0x6 {
+            return Ok(());
+        }
+        self.fmt_str(f, 0x4, "Manufacturer Name")?;
+        let b = self.data.bits[0x5];
+        if b & 1 != 0 {
+            writeln!(f, "Inbound Connection: Enabled")?;
+        } else {
+            writeln!(f, "Inbound Connection: Disabled")?;
+        }
+        if b & (1 << 1) != 0 {
+            writeln!(f, "Outbound Connection: Enabled")
+        } else {
+            writeln!(f, "Outbound Connection: Disabled")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table30() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                30,   // type
+                0x6,  // length
+                0xc0, // handle (2 bytes)
+                0x0,
+                1,   // manufacturer name string
+                0x1, // inbound enabled, outbound disabled
+            ]
+            .to_vec(),
+            strings: [String::from("Intel")].to_vec(),
+        };
+        let table = Table {
+            id: TableId::RemoteAccess,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Manufacturer Name: Intel"));
+        assert!(r.contains("Inbound Connection: Enabled"));
+        assert!(r.contains("Outbound Connection: Disabled"));
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use std::fmt;
+
+fn boot_status(b: u8) -> &'static str {
+    match b {
+        0 => "No errors detected",
+        1 => "No bootable media",
+        2 => "Operating system failed to load",
+        3 => "Firmware-detected hardware failure",
+        4 => "Operating system-detected hardware failure",
+        5 => "User-requested boot",
+        6 => "System security violation",
+        7 => "Previously-requested image",
+        8 => "System watchdog timer expired",
+        128..=191 => "Vendor/OEM-specific",
+        192..=255 => "Product-specific",
+        _ => "Reserved",
+    }
+}
+
+impl Table {
+    // The boot status, if this is a System Boot Information structure
+    pub fn boot_status(&self) -> Option<&'static str> {
+        if self.id() != 32 || self.size() < 0xb {
+            return None;
+        }
+        Some(boot_status(self.data.bits[0xa]))
+    }
+
+    pub fn fmt_table32(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 32 (System Boot Information)")?;
+        let len = usize::from(self.size());
+        let status = match self.boot_status() {
+            Some(s) => s,
+            None => return Ok(()),
+        };
+        writeln!(f, "Status: {} ({})", status, self.data.bits[0xa])?;
+        // Any remaining bytes are vendor or product-specific data
+        if len > 0xb {
+            write!(f, "Additional Status Data:")?;
+            for b in self.data.bits[0xb..len].iter() {
+                write!(f, " {:02x}", b)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn boot_table(bits: Vec<u8>) -> Table {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits,
+            strings: [].to_vec(),
+        };
+        Table {
+            id: TableId::BootInformation,
+            data: d,
+        }
+    }
+
+    #[test]
+    fn test_decode_table32() {
+        let table = boot_table([32, 0xb, 0xd0, 0x0, 0, 0, 0, 0, 0, 0, 8].to_vec());
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Status: System watchdog timer expired (8)"));
+        assert!(!r.contains("Additional Status Data"));
+        assert_eq!(table.boot_status(), Some("System watchdog timer expired"));
+    }
+
+    #[test]
+    fn test_decode_table32_oem_data() {
+        let table = boot_table([32, 0xd, 0xd0, 0x0, 0, 0, 0, 0, 0, 0, 130, 0xde, 0xad].to_vec());
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Status: Vendor/OEM-specific (130)"));
+        assert!(r.contains("Additional Status Data: de ad"));
+    }
+}
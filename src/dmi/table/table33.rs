@@ -0,0 +1,85 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use std::fmt;
+
+impl Table {
+    fn fmt_error_address64(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        offset: usize,
+        label: &str,
+    ) -> fmt::Result {
+        match self.qword(offset) {
+            0x8000000000000000 => writeln!(f, "{}: Unknown", label),
+            a => writeln!(f, "{}: 0x{:016x}", label, a),
+        }
+    }
+
+    pub fn fmt_table33(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 33 (64-Bit Memory Error Information)")?;
+        if self.size() < 0x1f {
+            return Ok(());
+        }
+        self.fmt_memory_error_common(f)?;
+        self.fmt_error_address64(f, 0xb, "Memory Array Error Address")?;
+        self.fmt_error_address64(f, 0x13, "Device Error Address")?;
+        self.fmt_error_resolution(f, 0x1b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table33() {
+        let mut bits = vec![0; 0x1f];
+        bits[0] = 33; // type
+        bits[1] = 0x1f; // length
+        bits[2] = 0x61; // handle
+        bits[0x4] = 0xe; // error type: uncorrectable
+        bits[0x5] = 0x4; // granularity: partition level
+        bits[0x6] = 0x4; // operation: write
+        bits[0x7] = 0x5a; // vendor syndrome
+        bits[0xb..0x13].clone_from_slice(&0x1_0000_2000u64.to_le_bytes());
+        bits[0x13..0x1b].clone_from_slice(&0x8000_0000_0000_0000u64.to_le_bytes());
+        bits[0x1b..0x1f].clone_from_slice(&0x8000_0000u32.to_le_bytes());
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits,
+            strings: [].to_vec(),
+        };
+        let table = Table {
+            id: TableId::MemoryError64,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Error Type: Uncorrectable error"));
+        assert!(r.contains("Error Granularity: Memory partition level"));
+        assert!(r.contains("Error Operation: Write"));
+        assert!(r.contains("Vendor Syndrome: 0x0000005a"));
+        assert!(r.contains("Memory Array Error Address: 0x0000000100002000"));
+        assert!(r.contains("Device Error Address: Unknown"));
+        assert!(r.contains("Error Resolution: Unknown"));
+        assert!(table.memory_error_recorded());
+    }
+}
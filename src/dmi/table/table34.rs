@@ -0,0 +1,122 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use std::fmt;
+
+fn management_device_type(b: u8) -> &'static str {
+    match b {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "National Semiconductor LM75",
+        0x4 => "National Semiconductor LM78",
+        0x5 => "National Semiconductor LM79",
+        0x6 => "National Semiconductor LM80",
+        0x7 => "National Semiconductor LM81",
+        0x8 => "Analog Devices ADM9240",
+        0x9 => "Dallas Semiconductor DS1780",
+        0xa => "Maxim 1617",
+        0xb => "Genesys GL518SM",
+        0xc => "Winbond W83781D",
+        0xd => "Holtek HT82H791",
+        _ => "Unrecognized device type. Probably a bug.",
+    }
+}
+
+fn management_address_type(b: u8) -> &'static str {
+    match b {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "I/O Port",
+        0x4 => "Memory",
+        0x5 => "SM Bus",
+        _ => "Unrecognized address type. Probably a bug.",
+    }
+}
+
+impl Table {
+    pub fn fmt_table34(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 34 (Management Device)")?;
+        if self.size() < 0xb {
+            return Ok(());
+        }
+        self.fmt_str(f, 0x4, "Description")?;
+        writeln!(
+            f,
+            "Type: {}",
+            management_device_type(self.data.bits[0x5])
+        )?;
+        writeln!(f, "Address: 0x{:08x}", self.dword(0x6))?;
+        writeln!(
+            f,
+            "Address Type: {}",
+            management_address_type(self.data.bits[0xa])
+        )
+    }
+
+    // Lists the components (Type 35) monitored by this device
+    pub fn fmt_table34_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        let components: Vec<&Table> = tables
+            .iter()
+            .filter(|t| t.id() == 35 && t.size() >= 0xb && t.word(0x5) == self.handle())
+            .collect();
+        if components.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "Monitored Components:")?;
+        for t in components {
+            writeln!(f, "  + {}", t.summary())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table34() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                34,   // type
+                0xb,  // length
+                0xe0, // handle (2 bytes)
+                0x0,
+                1,   // description string
+                0x4, // LM78
+                0x90, 0x02, 0x0, 0x0, // address
+                0x3, // I/O port
+            ]
+            .to_vec(),
+            strings: [String::from("LM78-1")].to_vec(),
+        };
+        let table = Table {
+            id: TableId::ManagementDevice,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Description: LM78-1"));
+        assert!(r.contains("Type: National Semiconductor LM78"));
+        assert!(r.contains("Address: 0x00000290"));
+        assert!(r.contains("Address Type: I/O Port"));
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::find_handle;
+use crate::dmi::table::probe::probe_units;
+use crate::dmi::table::Table;
+use std::fmt;
+
+impl Table {
+    fn management_device_handle(&self) -> u16 {
+        self.word(0x5)
+    }
+
+    fn component_handle(&self) -> u16 {
+        self.word(0x7)
+    }
+
+    fn threshold_handle(&self) -> u16 {
+        self.word(0x9)
+    }
+
+    pub fn fmt_table35(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 35 (Management Device Component)")?;
+        if self.size() < 0x9 {
+            return Ok(());
+        }
+        self.fmt_str(f, 0x4, "Description")?;
+        writeln!(
+            f,
+            "Management Device Handle: 0x{:04x}",
+            self.management_device_handle()
+        )?;
+        writeln!(f, "Component Handle: 0x{:04x}", self.component_handle())?;
+        if self.size() >= 0xb {
+            match self.threshold_handle() {
+                0xffff => writeln!(f, "Threshold Handle: None")?,
+                h => writeln!(f, "Threshold Handle: 0x{:04x}", h)?,
+            }
+        }
+        Ok(())
+    }
+
+    // Joins the monitored probe, the management device doing the
+    // monitoring, and the thresholds it enforces
+    pub fn fmt_table35_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        if self.size() < 0x9 {
+            return Ok(());
+        }
+        let sensor = find_handle(tables, self.component_handle());
+        match sensor {
+            Some(t) => writeln!(f, "Sensor: {}", t.summary())?,
+            None => writeln!(
+                f,
+                "Sensor: Handle 0x{:04x} not found",
+                self.component_handle()
+            )?,
+        }
+        match find_handle(tables, self.management_device_handle()) {
+            Some(t) => writeln!(f, "Monitor: {}", t.summary())?,
+            None => writeln!(
+                f,
+                "Monitor: Handle 0x{:04x} not found",
+                self.management_device_handle()
+            )?,
+        }
+        if self.size() < 0xb || self.threshold_handle() == 0xffff {
+            return writeln!(f, "Thresholds: None");
+        }
+        match find_handle(tables, self.threshold_handle()) {
+            Some(t) => {
+                writeln!(f, "Thresholds: {}", t.summary())?;
+                let units = sensor.and_then(|s| probe_units(s.id()));
+                t.fmt_thresholds(f, units, "  + ")
+            }
+            None => writeln!(
+                f,
+                "Thresholds: Handle 0x{:04x} not found",
+                self.threshold_handle()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::table;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn component() -> Table {
+        table(
+            TableId::ManagementComponent,
+            [
+                35,   // type
+                0xb,  // length
+                0xe1, // handle (2 bytes)
+                0x0,
+                1,    // description string
+                0xe0, // management device handle (2 bytes)
+                0x0,
+                0xa1, // component handle (2 bytes)
+                0x0,
+                0xe2, // threshold handle (2 bytes)
+                0x0,
+            ]
+            .to_vec(),
+            [String::from("CPU Temp Monitor")].to_vec(),
+        )
+    }
+
+    #[test]
+    fn test_decode_table35() {
+        let r = format!("{}", component());
+        println!("{}", r);
+        assert!(r.contains("Description: CPU Temp Monitor"));
+        assert!(r.contains("Management Device Handle: 0x00e0"));
+        assert!(r.contains("Component Handle: 0x00a1"));
+        assert!(r.contains("Threshold Handle: 0x00e2"));
+    }
+
+    #[test]
+    fn test_decode_table35_linked() {
+        let mut probe = vec![0; 0x14];
+        probe[0] = 28;
+        probe[1] = 0x14;
+        probe[2] = 0xa1;
+        probe[4] = 1;
+        let mut device = vec![0; 0xb];
+        device[0] = 34;
+        device[1] = 0xb;
+        device[2] = 0xe0;
+        device[4] = 1;
+        let mut thresholds = vec![0; 0x10];
+        thresholds[0] = 36;
+        thresholds[1] = 0x10;
+        thresholds[2] = 0xe2;
+        for i in (0x4..0x10).step_by(2) {
+            thresholds[i + 1] = 0x80; // unsupported
+        }
+        thresholds[0xa..0xc].clone_from_slice(&900u16.to_le_bytes());
+        let tables = [
+            table(TableId::TemperatureProbe, probe, [String::from("CPU Temp")].to_vec()),
+            table(TableId::ManagementDevice, device, [String::from("LM78-1")].to_vec()),
+            table(TableId::ManagementThresholds, thresholds, [].to_vec()),
+            component(),
+        ];
+        let r = format!("{}", tables[3].linked(&tables));
+        println!("{}", r);
+        assert!(r.contains("Sensor: Handle 0x00a1, Type 28 (Temperature Probe): CPU Temp"));
+        assert!(r.contains("Monitor: Handle 0x00e0, Type 34 (Management Device): LM78-1"));
+        assert!(r.contains("Thresholds: Handle 0x00e2, Type 36 (Management Device Threshold Data)"));
+        assert!(r.contains("  + Upper Critical Threshold: 90.0 °C"));
+        assert!(!r.contains("Lower Critical Threshold"));
+
+        let r = format!("{}", tables[1].linked(&tables));
+        println!("{}", r);
+        assert!(r.contains("Monitored Components:\n  + Handle 0x00e1, Type 35 (Management Device Component): CPU Temp Monitor"));
+    }
+}
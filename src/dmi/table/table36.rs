@@ -0,0 +1,109 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::probe::ProbeUnits;
+use crate::dmi::table::Table;
+use std::fmt;
+
+const THRESHOLD_UNSUPPORTED: u16 = 0x8000;
+
+impl Table {
+    // Thresholds are in the units of the monitored probe. When the
+    // probe is known they are scaled the same way its readings are;
+    // otherwise the raw values are shown.
+    pub fn fmt_thresholds(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        units: Option<&ProbeUnits>,
+        prefix: &str,
+    ) -> fmt::Result {
+        if self.size() < 0x10 {
+            return Ok(());
+        }
+        let labels = [
+            (0x4, "Lower Non-critical Threshold"),
+            (0x6, "Upper Non-critical Threshold"),
+            (0x8, "Lower Critical Threshold"),
+            (0xa, "Upper Critical Threshold"),
+            (0xc, "Lower Non-recoverable Threshold"),
+            (0xe, "Upper Non-recoverable Threshold"),
+        ];
+        for (offset, label) in labels.iter() {
+            let raw = self.word(*offset);
+            if raw == THRESHOLD_UNSUPPORTED {
+                continue;
+            }
+            match units {
+                Some(u) => writeln!(
+                    f,
+                    "{}{}: {:.*} {}",
+                    prefix,
+                    label,
+                    u.value_precision,
+                    f64::from(raw as i16) / u.value_divisor,
+                    u.value_unit
+                )?,
+                None => writeln!(f, "{}{}: {}", prefix, label, raw as i16)?,
+            }
+        }
+        Ok(())
+    }
+
+    pub fn fmt_table36(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 36 (Management Device Threshold Data)")?;
+        self.fmt_thresholds(f, None, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table36() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                36,   // type
+                0x10, // length
+                0xe2, // handle (2 bytes)
+                0x0,
+                0x00, 0x80, // lower non-critical: unsupported
+                0x52, 0x03, // upper non-critical
+                0x00, 0x80, // lower critical: unsupported
+                0x84, 0x03, // upper critical
+                0xf6, 0xff, // lower non-recoverable
+                0xb6, 0x03, // upper non-recoverable
+            ]
+            .to_vec(),
+            strings: [].to_vec(),
+        };
+        let table = Table {
+            id: TableId::ManagementThresholds,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(!r.contains("Lower Non-critical Threshold"));
+        assert!(r.contains("Upper Non-critical Threshold: 850"));
+        assert!(r.contains("Upper Critical Threshold: 900"));
+        assert!(r.contains("Lower Non-recoverable Threshold: -10"));
+        assert!(r.contains("Upper Non-recoverable Threshold: 950"));
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::find_handle;
+use crate::dmi::table::Table;
+use std::fmt;
+
+fn channel_type(b: u8) -> &'static str {
+    match b {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "RamBus",
+        0x4 => "SyncLink",
+        _ => "Unrecognized channel type. Probably a bug.",
+    }
+}
+
+impl Table {
+    // Each device is a load byte followed by a Type 17 handle
+    fn channel_devices(&self) -> Vec<(u8, u16)> {
+        if self.size() < 0x7 {
+            return Vec::new();
+        }
+        self.records(0x7, self.data.bits[0x6].into(), 3)
+            .iter()
+            .map(|r| (r[0], u16::from_le_bytes([r[1], r[2]])))
+            .collect()
+    }
+
+    // Number of populated devices and their total size in MB
+    fn channel_population(&self, tables: &[Table]) -> (usize, u64) {
+        let mut count = 0;
+        let mut size = 0;
+        for (_, handle) in self.channel_devices() {
+            if let Some(kb) = find_handle(tables, handle)
+                .filter(|t| t.id() == 17)
+                .and_then(|t| t.memory_device_size())
+            {
+                if kb > 0 {
+                    count += 1;
+                    size += kb / 1024;
+                }
+            }
+        }
+        (count, size)
+    }
+
+    pub fn fmt_table37(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 37 (Memory Channel)")?;
+        if self.size() < 0x7 {
+            return Ok(());
+        }
+        writeln!(f, "Type: {}", channel_type(self.data.bits[0x4]))?;
+        writeln!(f, "Maximal Load: {}", self.data.bits[0x5])?;
+        let devices = self.channel_devices();
+        writeln!(f, "Devices: {}", devices.len())?;
+        for (i, (load, handle)) in devices.iter().enumerate() {
+            writeln!(
+                f,
+                "Device {}: Load {}, handle 0x{:04x}",
+                i + 1,
+                load,
+                handle
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn fmt_table37_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        let devices = self.channel_devices();
+        if devices.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "Channel members:")?;
+        let mut load: u32 = 0;
+        for (l, handle) in devices.iter() {
+            load += u32::from(*l);
+            match find_handle(tables, *handle) {
+                Some(t) => match t.memory_device_size() {
+                    Some(0) => writeln!(f, "  + {} (empty)", t.summary())?,
+                    Some(kb) => writeln!(f, "  + {} ({} MB)", t.summary(), kb / 1024)?,
+                    None => writeln!(f, "  + {}", t.summary())?,
+                },
+                None => writeln!(f, "  + Handle 0x{:04x}: structure not found", handle)?,
+            }
+        }
+        if load > u32::from(self.data.bits[0x5]) {
+            writeln!(
+                f,
+                "Total load {} exceeds maximal load {}. Buggy firmware?",
+                load, self.data.bits[0x5]
+            )?;
+        }
+
+        // Compare with every other channel in the system
+        let (count, size) = self.channel_population(tables);
+        writeln!(f, "Populated: {} devices, {} MB", count, size)?;
+        let channels: Vec<&Table> = tables.iter().filter(|t| t.id() == 37).collect();
+        if channels.len() < 2 {
+            return Ok(());
+        }
+        let balanced = channels
+            .iter()
+            .all(|c| c.channel_population(tables) == (count, size));
+        if balanced {
+            writeln!(f, "Channel population is balanced across {} channels", channels.len())
+        } else {
+            writeln!(f, "Channel population is unbalanced:")?;
+            for c in channels {
+                let (count, size) = c.channel_population(tables);
+                writeln!(
+                    f,
+                    "  + Channel handle 0x{:04x}: {} devices, {} MB",
+                    c.handle(),
+                    count,
+                    size
+                )?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::table;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn dimm(handle: u8, size_mb: u16, locator: &str) -> Table {
+        let mut bits = vec![0; 0x15];
+        bits[0] = 17; // type
+        bits[1] = 0x15; // length
+        bits[2] = handle; // handle
+        bits[0x6] = 0xff; // no memory error
+        bits[0x7] = 0xff;
+        bits[0xc..0xe].clone_from_slice(&size_mb.to_le_bytes());
+        bits[0x10] = 1; // device locator string
+        table(TableId::MemoryDevice, bits, [String::from(locator)].to_vec())
+    }
+
+    fn channel(handle: u8, dimms: &[u8]) -> Table {
+        let mut bits = vec![37, 7 + 3 * dimms.len() as u8, handle, 0x0, 0x3, 4];
+        bits.push(dimms.len() as u8);
+        for d in dimms {
+            bits.extend_from_slice(&[1, *d, 0]);
+        }
+        table(TableId::MemoryChannel, bits, [].to_vec())
+    }
+
+    #[test]
+    fn test_decode_table37() {
+        let r = format!("{}", channel(0xf0, &[0x50, 0x51]));
+        println!("{}", r);
+        assert!(r.contains("Type: RamBus"));
+        assert!(r.contains("Maximal Load: 4"));
+        assert!(r.contains("Devices: 2"));
+        assert!(r.contains("Device 1: Load 1, handle 0x0050"));
+        assert!(r.contains("Device 2: Load 1, handle 0x0051"));
+    }
+
+    #[test]
+    fn test_decode_table37_unbalanced() {
+        let tables = [
+            dimm(0x50, 16384, "DIMM A1"),
+            dimm(0x51, 16384, "DIMM A2"),
+            dimm(0x52, 16384, "DIMM B1"),
+            dimm(0x53, 0, "DIMM B2"),
+            channel(0xf0, &[0x50, 0x51]),
+            channel(0xf1, &[0x52, 0x53]),
+        ];
+        let r = format!("{}", tables[5].linked(&tables));
+        println!("{}", r);
+        assert!(r.contains("Channel members:"));
+        assert!(r.contains("  + Handle 0x0052, Type 17 (Memory Device): DIMM B1 (16384 MB)"));
+        assert!(r.contains("  + Handle 0x0053, Type 17 (Memory Device): DIMM B2 (empty)"));
+        assert!(r.contains("Populated: 1 devices, 16384 MB"));
+        assert!(r.contains("Channel population is unbalanced:"));
+        assert!(r.contains("  + Channel handle 0x00f0: 2 devices, 32768 MB"));
+    }
+
+    #[test]
+    fn test_decode_table37_balanced() {
+        let tables = [
+            dimm(0x50, 16384, "DIMM A1"),
+            dimm(0x52, 16384, "DIMM B1"),
+            channel(0xf0, &[0x50]),
+            channel(0xf1, &[0x52]),
+        ];
+        let r = format!("{}", tables[2].linked(&tables));
+        println!("{}", r);
+        assert!(r.contains("Channel population is balanced across 2 channels"));
+    }
+}
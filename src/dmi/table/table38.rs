@@ -0,0 +1,186 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use std::fmt;
+
+const IPMI_SSIF: u8 = 0x4;
+
+fn ipmi_interface_type(b: u8) -> &'static str {
+    match b {
+        0x0 => "Unknown",
+        0x1 => "KCS (Keyboard Controller Style)",
+        0x2 => "SMIC (Server Management Interface Chip)",
+        0x3 => "BT (Block Transfer)",
+        IPMI_SSIF => "SSIF (SMBus System Interface)",
+        _ => "Unrecognized interface type. Probably a bug.",
+    }
+}
+
+impl Table {
+    fn fmt_ipmi_base_address(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let raw = self.qword(0x8);
+        if self.data.bits[0x4] == IPMI_SSIF {
+            // The low byte holds the SMBus target address
+            return writeln!(f, "Base Address: 0x{:02x} (SMBus)", (raw & 0xff) >> 1);
+        }
+        // Bit 0 selects I/O or memory space. The real bit 0 of the
+        // address is in the base address modifier byte.
+        let mut address = raw & !1;
+        if self.size() > 0x10 && self.data.bits[0x10] & (1 << 4) != 0 {
+            address |= 1;
+        }
+        if raw & 1 != 0 {
+            writeln!(f, "Base Address: 0x{:016x} (I/O)", address)
+        } else {
+            writeln!(f, "Base Address: 0x{:016x} (Memory-mapped)", address)
+        }
+    }
+
+    fn fmt_ipmi_interrupt_info(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let b = self.data.bits[0x10];
+        let spacing: &str = match b >> 6 {
+            0x0 => "Successive Byte Boundaries",
+            0x1 => "32-bit Boundaries",
+            0x2 => "16-byte Boundaries",
+            _ => "Reserved",
+        };
+        writeln!(f, "Register Spacing: {}", spacing)?;
+        if b & (1 << 3) == 0 {
+            return writeln!(f, "Interrupt Information: Not Specified");
+        }
+        if b & (1 << 1) != 0 {
+            writeln!(f, "Interrupt Polarity: Active High")?;
+        } else {
+            writeln!(f, "Interrupt Polarity: Active Low")?;
+        }
+        if b & 1 != 0 {
+            writeln!(f, "Interrupt Trigger Mode: Level")?;
+        } else {
+            writeln!(f, "Interrupt Trigger Mode: Edge")?;
+        }
+        if self.size() > 0x11 {
+            match self.data.bits[0x11] {
+                0 => writeln!(f, "Interrupt Number: Unspecified")?,
+                n => writeln!(f, "Interrupt Number: {}", n)?,
+            }
+        }
+        Ok(())
+    }
+
+    pub fn fmt_table38(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 38 (IPMI Device Information)")?;
+        let len = self.size();
+        if len < 0x10 {
+            return Ok(());
+        }
+        writeln!(
+            f,
+            "Interface Type: {}",
+            ipmi_interface_type(self.data.bits[0x4])
+        )?;
+        let rev = self.data.bits[0x5];
+        writeln!(f, "Specification Version: {}.{}", rev >> 4, rev & 0xf)?;
+        writeln!(f, "I2C Target Address: 0x{:02x}", self.data.bits[0x6] >> 1)?;
+        match self.data.bits[0x7] {
+            0xff => writeln!(f, "NV Storage Device: Not Present")?,
+            a => writeln!(f, "NV Storage Device Address: {}", a)?,
+        }
+        self.fmt_ipmi_base_address(f)?;
+        if len > 0x10 && self.data.bits[0x4] != IPMI_SSIF {
+            self.fmt_ipmi_interrupt_info(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table38_kcs() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                38,   // type
+                0x12, // length
+                0xc1, // handle (2 bytes)
+                0x0,
+                0x1,  // KCS
+                0x20, // IPMI 2.0
+                0x20, // I2C target address
+                0xff, // no NV storage device
+                0xa3, 0x0c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, // base address 0xca2, I/O
+                (1 << 3) | (1 << 1) | 1, // interrupt info, active high, level
+                0xa, // interrupt number
+            ]
+            .to_vec(),
+            strings: [].to_vec(),
+        };
+        let table = Table {
+            id: TableId::IpmiDevice,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Interface Type: KCS (Keyboard Controller Style)"));
+        assert!(r.contains("Specification Version: 2.0"));
+        assert!(r.contains("I2C Target Address: 0x10"));
+        assert!(r.contains("NV Storage Device: Not Present"));
+        assert!(r.contains("Base Address: 0x0000000000000ca2 (I/O)"));
+        assert!(r.contains("Register Spacing: Successive Byte Boundaries"));
+        assert!(r.contains("Interrupt Polarity: Active High"));
+        assert!(r.contains("Interrupt Trigger Mode: Level"));
+        assert!(r.contains("Interrupt Number: 10"));
+    }
+
+    #[test]
+    fn test_decode_table38_ssif() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                38,   // type
+                0x10, // length
+                0xc2, // handle (2 bytes)
+                0x0,
+                0x4,  // SSIF
+                0x15, // IPMI 1.5
+                0x20, // I2C target address
+                0x0,  // NV storage device address
+                0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, // SMBus address
+            ]
+            .to_vec(),
+            strings: [].to_vec(),
+        };
+        let table = Table {
+            id: TableId::IpmiDevice,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Interface Type: SSIF (SMBus System Interface)"));
+        assert!(r.contains("Specification Version: 1.5"));
+        assert!(r.contains("NV Storage Device Address: 0"));
+        assert!(r.contains("Base Address: 0x10 (SMBus)"));
+        assert!(!r.contains("Register Spacing"));
+    }
+}
@@ -0,0 +1,265 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::decode_byte;
+use crate::dmi::table::find_handle;
+use crate::dmi::table::Table;
+use std::fmt;
+
+fn power_supply_type(v: u16) -> &'static str {
+    match v {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "Linear",
+        0x4 => "Switching",
+        0x5 => "Battery",
+        0x6 => "UPS",
+        0x7 => "Converter",
+        0x8 => "Regulator",
+        _ => "Unrecognized type. Probably a bug.",
+    }
+}
+
+fn power_supply_status(v: u16) -> &'static str {
+    match v {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "OK",
+        0x4 => "Non-critical",
+        0x5 => "Critical",
+        _ => "Unrecognized status. Probably a bug.",
+    }
+}
+
+fn input_voltage_range_switching(v: u16) -> &'static str {
+    match v {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "Manual",
+        0x4 => "Auto-switch",
+        0x5 => "Wide range",
+        0x6 => "Not applicable",
+        _ => "Unrecognized value. Probably a bug.",
+    }
+}
+
+impl Table {
+    // Zero means the supply isn't part of a redundant power unit
+    fn power_unit_group(&self) -> u8 {
+        self.data.bits[0x4]
+    }
+
+    fn power_supply_characteristics(&self) -> u16 {
+        self.word(0xe)
+    }
+
+    // Present, plugged in, and reporting OK status
+    fn power_supply_healthy(&self) -> bool {
+        let c = self.power_supply_characteristics();
+        c & (1 << 1) != 0 && c & (1 << 2) == 0 && (c >> 7) & 7 == 0x3
+    }
+
+    fn fmt_power_supply_characteristics(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = self.power_supply_characteristics();
+        writeln!(f, "Type: {}", power_supply_type((c >> 10) & 0xf))?;
+        writeln!(f, "Status: {}", power_supply_status((c >> 7) & 7))?;
+        writeln!(
+            f,
+            "Input Voltage Range Switching: {}",
+            input_voltage_range_switching((c >> 3) & 0xf)
+        )?;
+        let bit_strings = [
+            (1, "Hot-replaceable"),
+            (1 << 1, "Present"),
+            (1 << 2, "Unplugged from the wall"),
+        ];
+        writeln!(f, "Characteristics:")?;
+        decode_byte(f, (c & 7) as u8, &bit_strings)
+    }
+
+    fn fmt_power_supply_handle(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        offset: usize,
+        label: &str,
+    ) -> fmt::Result {
+        match self.word(offset) {
+            0xffff => writeln!(f, "{} Handle: None", label),
+            h => writeln!(f, "{} Handle: 0x{:04x}", label, h),
+        }
+    }
+
+    pub fn fmt_table39(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 39 (System Power Supply)")?;
+        let len = self.size();
+        if len < 0x10 {
+            return Ok(());
+        }
+        match self.power_unit_group() {
+            0 => writeln!(f, "Power Unit Group: None")?,
+            g => writeln!(f, "Power Unit Group: {}", g)?,
+        }
+        self.fmt_str(f, 0x5, "Location")?;
+        self.fmt_str(f, 0x6, "Name")?;
+        self.fmt_str(f, 0x7, "Manufacturer")?;
+        self.fmt_str(f, 0x8, "Serial Number")?;
+        self.fmt_str(f, 0x9, "Asset Tag")?;
+        self.fmt_str(f, 0xa, "Model Part Number")?;
+        self.fmt_str(f, 0xb, "Revision")?;
+        match self.word(0xc) {
+            0x8000 => writeln!(f, "Max Power Capacity: Unknown")?,
+            w => writeln!(f, "Max Power Capacity: {} W", w)?,
+        }
+        self.fmt_power_supply_characteristics(f)?;
+        if len >= 0x16 {
+            self.fmt_power_supply_handle(f, 0x10, "Input Voltage Probe")?;
+            self.fmt_power_supply_handle(f, 0x12, "Cooling Device")?;
+            self.fmt_power_supply_handle(f, 0x14, "Input Current Probe")?;
+        }
+        Ok(())
+    }
+
+    pub fn fmt_table39_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        if self.size() < 0x10 {
+            return Ok(());
+        }
+        if self.size() >= 0x16 {
+            let handles = [
+                (0x10, "Input Voltage Probe"),
+                (0x12, "Cooling Device"),
+                (0x14, "Input Current Probe"),
+            ];
+            for (offset, label) in handles.iter() {
+                match self.word(*offset) {
+                    0xffff => (),
+                    h => match find_handle(tables, h) {
+                        Some(t) => writeln!(f, "{}: {}", label, t.summary())?,
+                        None => writeln!(f, "{}: Handle 0x{:04x} not found", label, h)?,
+                    },
+                }
+            }
+        }
+
+        let group = self.power_unit_group();
+        if group == 0 {
+            return writeln!(f, "Redundancy: Not part of a redundant power unit");
+        }
+        let members: Vec<&Table> = tables
+            .iter()
+            .filter(|t| t.id() == 39 && t.size() >= 0x10 && t.power_unit_group() == group)
+            .collect();
+        writeln!(f, "Power Unit Group {} members:", group)?;
+        for t in members.iter() {
+            if t.power_supply_healthy() {
+                writeln!(f, "  + {} (healthy)", t.summary())?;
+            } else {
+                writeln!(f, "  + {} (not healthy)", t.summary())?;
+            }
+        }
+        let healthy = members.iter().filter(|t| t.power_supply_healthy()).count();
+        let redundancy: &str = if healthy < 2 {
+            "Lost"
+        } else if healthy < members.len() {
+            "Degraded"
+        } else {
+            "Full"
+        };
+        writeln!(
+            f,
+            "Redundancy: {} ({} of {} supplies healthy)",
+            redundancy,
+            healthy,
+            members.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::table;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    // Switching supply, OK, auto-switch, hot-replaceable and present
+    const HEALTHY: u16 = (0x4 << 10) | (0x3 << 7) | (0x4 << 3) | 0x3;
+
+    fn psu(handle: u8, group: u8, characteristics: u16, name: &str) -> Table {
+        let mut bits = vec![0; 0x16];
+        bits[0] = 39; // type
+        bits[1] = 0x16; // length
+        bits[2] = handle; // handle
+        bits[0x4] = group; // power unit group
+        bits[0x5] = 1; // location string
+        bits[0x6] = 2; // device name string
+        bits[0x7] = 3; // manufacturer string
+        bits[0xc..0xe].clone_from_slice(&750u16.to_le_bytes());
+        bits[0xe..0x10].clone_from_slice(&characteristics.to_le_bytes());
+        bits[0x10..0x12].clone_from_slice(&0x00a2u16.to_le_bytes()); // voltage probe
+        bits[0x12..0x14].clone_from_slice(&0xffffu16.to_le_bytes()); // no cooling device
+        bits[0x14..0x16].clone_from_slice(&0xffffu16.to_le_bytes()); // no current probe
+        table(
+            TableId::PowerSupply,
+            bits,
+            [
+                String::from("Rear"),
+                String::from(name),
+                String::from("Delta"),
+            ]
+            .to_vec(),
+        )
+    }
+
+    #[test]
+    fn test_decode_table39() {
+        let r = format!("{}", psu(0x90, 1, HEALTHY, "PSU1"));
+        println!("{}", r);
+        assert!(r.contains("Power Unit Group: 1"));
+        assert!(r.contains("Location: Rear"));
+        assert!(r.contains("Name: PSU1"));
+        assert!(r.contains("Manufacturer: Delta"));
+        assert!(r.contains("Serial Number: Unspecified"));
+        assert!(r.contains("Max Power Capacity: 750 W"));
+        assert!(r.contains("Type: Switching"));
+        assert!(r.contains("Status: OK"));
+        assert!(r.contains("Input Voltage Range Switching: Auto-switch"));
+        assert!(r.contains("  + Hot-replaceable\n  + Present\n"));
+        assert!(!r.contains("Unplugged"));
+        assert!(r.contains("Input Voltage Probe Handle: 0x00a2"));
+        assert!(r.contains("Cooling Device Handle: None"));
+    }
+
+    #[test]
+    fn test_decode_table39_redundancy() {
+        let mut probe = vec![0; 0x14];
+        probe[0] = 26;
+        probe[1] = 0x14;
+        probe[2] = 0xa2;
+        probe[4] = 1;
+        // Second supply is present but unplugged
+        let tables = [
+            table(TableId::VoltageProbe, probe, [String::from("PSU Vin")].to_vec()),
+            psu(0x90, 1, HEALTHY, "PSU1"),
+            psu(0x91, 1, HEALTHY | (1 << 2), "PSU2"),
+        ];
+        let r = format!("{}", tables[1].linked(&tables));
+        println!("{}", r);
+        assert!(r.contains("Input Voltage Probe: Handle 0x00a2, Type 26 (Voltage Probe): PSU Vin"));
+        assert!(r.contains("Power Unit Group 1 members:"));
+        assert!(r.contains("  + Handle 0x0090, Type 39 (System Power Supply): PSU1 (healthy)"));
+        assert!(r.contains("  + Handle 0x0091, Type 39 (System Power Supply): PSU2 (not healthy)"));
+        assert!(r.contains("Redundancy: Lost (1 of 2 supplies healthy)"));
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use std::fmt;
+
+fn processor_type(b: u8) -> &'static str {
+    match b {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "Central Processor",
+        0x4 => "Math Processor",
+        0x5 => "DSP Processor",
+        0x6 => "Video Processor",
+        _ => "Unrecognized processor type. Probably a bug.",
+    }
+}
+
+impl Table {
+    fn fmt_processor_speed(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        offset: usize,
+        label: &str,
+    ) -> fmt::Result {
+        match self.word(offset) {
+            0 => writeln!(f, "{}: Unknown", label),
+            mhz => writeln!(f, "{}: {} MHz", label, mhz),
+        }
+    }
+
+    fn fmt_processor_status(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let b = self.data.bits[0x18];
+        if b & (1 << 6) == 0 {
+            return writeln!(f, "Status: Unpopulated");
+        }
+        let status: &str = match b & 0x7 {
+            0x0 => "Unknown",
+            0x1 => "Enabled",
+            0x2 => "Disabled By User",
+            0x3 => "Disabled By BIOS (POST Error)",
+            0x4 => "Idle",
+            0x7 => "Other",
+            _ => "Unrecognized status. Probably a bug.",
+        };
+        writeln!(f, "Status: Populated, {}", status)
+    }
+
+    pub fn fmt_table4(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 4 (Processor Information)")?;
+        let len = self.size();
+        if len < 0x1a {
+            return Ok(());
+        }
+        self.fmt_str(f, 0x4, "Socket Designation")?;
+        writeln!(f, "Type: {}", processor_type(self.data.bits[0x5]))?;
+        self.fmt_str(f, 0x7, "Manufacturer")?;
+        self.fmt_str(f, 0x10, "Version")?;
+        self.fmt_processor_speed(f, 0x12, "External Clock")?;
+        self.fmt_processor_speed(f, 0x14, "Max Speed")?;
+        self.fmt_processor_speed(f, 0x16, "Current Speed")?;
+        self.fmt_processor_status(f)?;
+        // SMBIOS 2.3+
+        if len >= 0x23 {
+            self.fmt_str(f, 0x20, "Serial Number")?;
+            self.fmt_str(f, 0x21, "Asset Tag")?;
+            self.fmt_str(f, 0x22, "Part Number")?;
+        }
+        // SMBIOS 2.5+
+        if len >= 0x26 {
+            writeln!(f, "Core Count: {}", self.data.bits[0x23])?;
+            writeln!(f, "Core Enabled: {}", self.data.bits[0x24])?;
+            writeln!(f, "Thread Count: {}", self.data.bits[0x25])?;
+        }
+        // TODO: decode family, ID, voltage, cache handles and characteristics
+        Ok(())
+    }
+
+    pub fn fmt_table4_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        for t in tables.iter().filter(|t| t.id() == 44) {
+            if t.size() < 0x8 || t.word(0x4) != self.handle() {
+                continue;
+            }
+            writeln!(f, "Additional Information: {}", t.summary())?;
+            t.fmt_processor_block(f, "  ")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    #[test]
+    fn test_decode_table4() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                4,    // type
+                0x28, // length
+                0x0,  // handle (2 bytes)
+                0x4,
+                1,    // socket designation
+                0x3,  // central processor
+                0x1,  // family
+                2,    // manufacturer
+                0, 0, 0, 0, 0, 0, 0, 0, // ID
+                3,    // version
+                0,    // voltage
+                0x64, 0, // external clock
+                0x70, 0x17, // max speed
+                0, 0, // current speed
+                0x41, // populated, enabled
+                0x1,  // upgrade
+                0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // cache handles
+                0, 0, 0, // serial, asset tag, part number
+                4,    // core count
+                4,    // core enabled
+                8,    // thread count
+                0x4, 0x0, // characteristics
+            ]
+            .to_vec(),
+            strings: [
+                String::from("CPU0"),
+                String::from("SiFive"),
+                String::from("U74-MC"),
+            ]
+            .to_vec(),
+        };
+        let table = Table {
+            id: TableId::Processor,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Socket Designation: CPU0\nType: Central Processor"));
+        assert!(r.contains("Version: U74-MC"));
+        assert!(r.contains("Max Speed: 6000 MHz\nCurrent Speed: Unknown"));
+        assert!(r.contains("Status: Populated, Enabled"));
+        assert!(r.contains("Serial Number: Unspecified"));
+        assert!(r.contains("Thread Count: 8"));
+    }
+}
@@ -0,0 +1,346 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::find_handle;
+use crate::dmi::table::Table;
+use std::fmt;
+
+// Each entry is: length, referenced handle (2 bytes), referenced
+// offset, string number, then a value that fills the rest of the entry
+const ENTRY_HEADER_LEN: usize = 5;
+
+pub struct AdditionalInfo<'a> {
+    pub handle: u16,
+    pub offset: u8,
+    pub string: &'a str,
+    pub value: &'a [u8],
+}
+
+impl fmt::Display for AdditionalInfo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.string)?;
+        match self.value.len() {
+            0 => write!(f, "No value"),
+            1 => write!(f, "0x{:02x}", self.value[0]),
+            2 => write!(f, "0x{:04x}", u16::from_le_bytes([self.value[0], self.value[1]])),
+            4 => {
+                let mut bytes: [u8; 4] = [0; 4];
+                bytes.clone_from_slice(self.value);
+                write!(f, "0x{:08x}", u32::from_le_bytes(bytes))
+            }
+            _ => {
+                for (i, b) in self.value.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{:02x}", b)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Table {
+    // Entries are variable length, so they can't be read with records()
+    pub fn additional_info_entries(&self) -> Vec<AdditionalInfo<'_>> {
+        let mut entries = Vec::new();
+        if self.id() != 40 || self.size() < 0x5 {
+            return entries;
+        }
+        let end = usize::from(self.size()).min(self.data.bits.len());
+        let mut pos = 0x5;
+        for _i in 0..self.data.bits[0x4] {
+            let len = match self.data.bits.get(pos) {
+                Some(l) => usize::from(*l),
+                None => break,
+            };
+            if len < ENTRY_HEADER_LEN || pos + len > end {
+                warn!("Malformed additional information entry at offset 0x{:x}", pos);
+                break;
+            }
+            entries.push(AdditionalInfo {
+                handle: self.word(pos + 1),
+                offset: self.data.bits[pos + 3],
+                string: self.string_value(self.data.bits[pos + 4].into()),
+                value: &self.data.bits[pos + ENTRY_HEADER_LEN..pos + len],
+            });
+            pos += len;
+        }
+        entries
+    }
+
+    pub fn fmt_table40(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 40 (Additional Information)")?;
+        let entries = self.additional_info_entries();
+        writeln!(f, "Entries: {}", entries.len())?;
+        for (i, e) in entries.iter().enumerate() {
+            writeln!(
+                f,
+                "Entry {}: handle 0x{:04x}, offset 0x{:02x}, {}",
+                i + 1,
+                e.handle,
+                e.offset,
+                e
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn fmt_table40_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        let entries = self.additional_info_entries();
+        if entries.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "Referenced structures:")?;
+        for e in entries.iter() {
+            match find_handle(tables, e.handle) {
+                Some(t) => writeln!(f, "  + {}", t.summary())?,
+                None => writeln!(f, "  + Handle 0x{:04x}: structure not found", e.handle)?,
+            }
+        }
+        Ok(())
+    }
+
+    // Index of the output line that shows the field at the given
+    // offset, if the decoder has one. Bit flags listed under a field
+    // belong to it, so the entry goes after them.
+    fn field_line(&self, lines: &[&str], offset: u8) -> Option<usize> {
+        // Type, length and handle belong to the title line
+        if offset < 0x4 {
+            return Some(0);
+        }
+        let (_, label) = self.field_labels().iter().find(|(o, _)| *o == offset)?;
+        let start = lines.iter().position(|l| l.starts_with(label))?;
+        let flags = lines[start + 1..]
+            .iter()
+            .take_while(|l| l.starts_with("  + "))
+            .count();
+        Some(start + flags)
+    }
+
+    // Prints this structure with every Type 40 entry that refers to it
+    // placed after the line showing the referenced field. Entries for
+    // fields the decoder doesn't label are listed at the end.
+    pub fn fmt_with_additional_info(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        tables: &[Table],
+    ) -> fmt::Result {
+        let entries: Vec<AdditionalInfo<'_>> = tables
+            .iter()
+            .filter(|t| t.id() == 40)
+            .flat_map(|t| t.additional_info_entries())
+            .filter(|e| e.handle == self.handle())
+            .collect();
+        if entries.is_empty() {
+            return write!(f, "{}", self);
+        }
+        let out = format!("{}", self);
+        let lines: Vec<&str> = out.lines().collect();
+        let placed: Vec<Option<usize>> = entries
+            .iter()
+            .map(|e| self.field_line(&lines, e.offset))
+            .collect();
+        for (i, line) in lines.iter().enumerate() {
+            writeln!(f, "{}", line)?;
+            for (e, _) in entries.iter().zip(placed.iter()).filter(|(_, p)| **p == Some(i)) {
+                writeln!(f, "  + Additional Information: {}", e)?;
+            }
+        }
+        let mut header = false;
+        for (e, _) in entries.iter().zip(placed.iter()).filter(|(_, p)| p.is_none()) {
+            if !header {
+                writeln!(f, "Additional Information:")?;
+                header = true;
+            }
+            let offset = usize::from(e.offset);
+            if offset < usize::from(self.size()) && offset < self.data.bits.len() {
+                writeln!(
+                    f,
+                    "  + Offset 0x{:02x} (field value 0x{:02x}): {}",
+                    e.offset, self.data.bits[offset], e
+                )?;
+            } else {
+                writeln!(
+                    f,
+                    "  + Offset 0x{:02x} (beyond end of structure. Buggy firmware?): {}",
+                    e.offset, e
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::table;
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn additional_info() -> Table {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                40,   // type
+                0x11, // length
+                0x10, // handle (2 bytes)
+                0x1,
+                2,    // number of entries
+                6,    // entry 1 length
+                0x0,  // referenced handle (2 bytes)
+                0x2,
+                0x5,  // referenced offset
+                1,    // string
+                0x7,  // value
+                6,    // entry 2 length
+                0x0,  // referenced handle (2 bytes)
+                0x2,
+                0x9,  // referenced offset
+                2,    // string
+                0x2a, // value
+            ]
+            .to_vec(),
+            strings: [
+                String::from("Board Revision"),
+                String::from("Beyond"),
+            ]
+            .to_vec(),
+        };
+        Table {
+            id: TableId::AdditionalInformation,
+            data: d,
+        }
+    }
+
+    #[test]
+    fn test_decode_table40() {
+        let r = format!("{}", additional_info());
+        println!("{}", r);
+        assert!(r.contains("Entries: 2"));
+        assert!(r.contains("Entry 1: handle 0x0200, offset 0x05, Board Revision: 0x07"));
+        assert!(r.contains("Entry 2: handle 0x0200, offset 0x09, Beyond: 0x2a"));
+    }
+
+    #[test]
+    fn test_additional_info_inline() {
+        let port = Table {
+            id: TableId::PortConnector,
+            data: Data {
+                location: 0,
+                string_location: 0,
+                next_loc: 0,
+                bits: [8, 0x9, 0x0, 0x2, 0, 0x22, 0, 0, 0x20].to_vec(),
+                strings: [].to_vec(),
+            },
+        };
+        let tables = [port, additional_info()];
+        let r = format!("{}", tables[0].linked(&tables));
+        println!("{}", r);
+        // Offset 0x5 is the internal connector type
+        let lines: Vec<&str> = r.lines().collect();
+        let field = lines
+            .iter()
+            .position(|l| l.starts_with("Internal Connector Type:"))
+            .unwrap();
+        assert_eq!(lines[field + 1], "  + Additional Information: Board Revision: 0x07");
+        assert!(lines[field + 2].starts_with("External Reference Designator"));
+        assert!(r.contains(
+            "Additional Information:\n  \
+             + Offset 0x09 (beyond end of structure. Buggy firmware?): Beyond: 0x2a"
+        ));
+
+        let r = format!("{}", tables[1].linked(&tables));
+        println!("{}", r);
+        assert!(r.contains(
+            "Referenced structures:\n  + Handle 0x0200, Type 8 (Port Connector Information)"
+        ));
+    }
+
+    #[test]
+    fn test_additional_info_field_offsets() {
+        let chassis = table(
+            TableId::Chassis,
+            vec![
+                3,    // type
+                0x19, // length
+                0x0,  // handle (2 bytes)
+                0x3,
+                0,    // manufacturer string
+                0x3,  // type
+                0,    // version string
+                0,    // serial number string
+                0,    // asset tag string
+                0x3,  // boot up state
+                0x3,  // power supply state
+                0x3,  // thermal state
+                0x3,  // security status
+                0x0,  // OEM defined (4 bytes)
+                0x0,
+                0x0,
+                0x0,
+                0,    // height
+                0,    // number of power cords
+                1,    // contained element count
+                3,    // contained element size
+                0x81, // contained element: Type 1
+                0x1,  // minimum
+                0x1,  // maximum
+                0,    // SKU string
+            ],
+            vec![],
+        );
+        let info = table(
+            TableId::AdditionalInformation,
+            vec![
+                40,   // type
+                0x11, // length
+                0x10, // handle (2 bytes)
+                0x1,
+                2,    // number of entries
+                6,    // entry 1 length
+                0x0,  // referenced handle (2 bytes)
+                0x3,
+                0x13, // referenced offset: contained element count
+                1,    // string
+                0x1,  // value
+                6,    // entry 2 length
+                0x0,  // referenced handle (2 bytes)
+                0x3,
+                0xd,  // referenced offset: OEM defined, not shown
+                1,    // string
+                0x2,  // value
+            ],
+            vec![String::from("Elements")],
+        );
+        let tables = [chassis, info];
+        let r = format!("{}", tables[0].linked(&tables));
+        println!("{}", r);
+        let lines: Vec<&str> = r.lines().collect();
+        let field = lines
+            .iter()
+            .position(|l| l.starts_with("Contained elements:"))
+            .unwrap();
+        assert_eq!(lines[field + 1], "  + Additional Information: Elements: 0x01");
+        assert!(r.contains(
+            "Additional Information:\n  + Offset 0x0d (field value 0x00): Elements: 0x02"
+        ));
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::table10::onboard_device_status;
+use crate::dmi::table::table10::onboard_device_type;
+use crate::dmi::table::Table;
+use std::fmt;
+
+impl Table {
+    fn fmt_onboard_reference_designation(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_str(f, 0x4, "Reference Designation")
+    }
+
+    // Formats the PCI address as segment:bus:device.function, the same
+    // form used by lspci -D and sysfs.
+    fn fmt_onboard_bus_address(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let segment = self.word(0x7);
+        let bus = self.data.bits[0x9];
+        let devfn = self.data.bits[0xa];
+        if segment == 0xffff && bus == 0xff && devfn == 0xff {
+            return writeln!(f, "Bus Address: Not a PCI device");
+        }
+        writeln!(
+            f,
+            "Bus Address: {:04x}:{:02x}:{:02x}.{:x}",
+            segment,
+            bus,
+            devfn >> 3,
+            devfn & 7
+        )
+    }
+
+    pub fn fmt_table41(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 41 (Onboard Devices Extended Information)")?;
+        let len: u8 = self.size();
+        if len < 0xb {
+            return Ok(());
+        }
+        let b = self.data.bits[0x5];
+        self.fmt_onboard_reference_designation(f)?;
+        writeln!(f, "Type: {}", onboard_device_type(b))?;
+        writeln!(f, "Status: {}", onboard_device_status(b))?;
+        writeln!(f, "Type Instance: {}", self.data.bits[0x6])?;
+        self.fmt_onboard_bus_address(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table41() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                41,   // type
+                0xb,  // length
+                0x30, // handle (2 bytes)
+                0x0,
+                1,         // reference designation string
+                128 | 0x5, // enabled ethernet device
+                1,         // device type instance
+                0x0,       // segment group number (2 bytes)
+                0x0,
+                0x0,           // bus number
+                0x1f << 3 | 6, // device 0x1f, function 6
+            ]
+            .to_vec(),
+            strings: [String::from("Onboard LAN")].to_vec(),
+        };
+        let table = Table {
+            id: TableId::OnboardDevicesExtended,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Reference Designation: Onboard LAN"));
+        assert!(r.contains("Type: Ethernet"));
+        assert!(r.contains("Status: Enabled"));
+        assert!(r.contains("Type Instance: 1"));
+        assert!(r.contains("Bus Address: 0000:00:1f.6"));
+    }
+
+    #[test]
+    fn test_decode_table41_not_pci() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [41, 0xb, 0x31, 0x0, 0, 0x7, 1, 0xff, 0xff, 0xff, 0xff].to_vec(),
+            strings: [].to_vec(),
+        };
+        let table = Table {
+            id: TableId::OnboardDevicesExtended,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Reference Designation: Unspecified"));
+        assert!(r.contains("Type: Sound"));
+        assert!(r.contains("Status: Disabled"));
+        assert!(r.contains("Bus Address: Not a PCI device"));
+    }
+}
@@ -0,0 +1,453 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::decode_byte;
+use crate::dmi::table::dword_at;
+use crate::dmi::table::uuid_string;
+use crate::dmi::table::word_at;
+use crate::dmi::table::Table;
+use std::fmt;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+
+const NETWORK_HOST_INTERFACE: u8 = 0x40;
+const REDFISH_OVER_IP: u8 = 0x4;
+
+fn interface_type(b: u8) -> &'static str {
+    // Values are shared with the MCTP host interface types (DSP0239)
+    match b {
+        0x02 => "KCS: Keyboard Controller Style",
+        0x03 => "8250 UART Register Compatible",
+        0x04 => "16450 UART Register Compatible",
+        0x05 => "16550/16550A UART Register Compatible",
+        0x06 => "16650/16650A UART Register Compatible",
+        0x07 => "16750/16750A UART Register Compatible",
+        0x08 => "16850/16850A UART Register Compatible",
+        NETWORK_HOST_INTERFACE => "Network",
+        0xf0 => "OEM",
+        _ => "Unrecognized interface type. Probably a bug.",
+    }
+}
+
+fn protocol_type(b: u8) -> &'static str {
+    match b {
+        0x02 => "IPMI",
+        0x03 => "MCTP",
+        REDFISH_OVER_IP => "Redfish over IP",
+        0xf0 => "OEM",
+        _ => "Unrecognized protocol type. Probably a bug.",
+    }
+}
+
+fn ip_assignment_type(b: u8) -> &'static str {
+    match b {
+        0x0 => "Unknown",
+        0x1 => "Static",
+        0x2 => "DHCP",
+        0x3 => "AutoConf",
+        0x4 => "Host Selected",
+        _ => "Unrecognized assignment type. Probably a bug.",
+    }
+}
+
+fn mac_string(b: &[u8]) -> String {
+    b.iter()
+        .map(|x| format!("{:02x}", x))
+        .collect::<Vec<String>>()
+        .join(":")
+}
+
+// Addresses are always 16 bytes; IPv4 uses the first four
+fn ip_string(format: u8, b: &[u8]) -> Option<String> {
+    match format {
+        0x1 => Some(Ipv4Addr::new(b[0], b[1], b[2], b[3]).to_string()),
+        0x2 => {
+            let mut octets: [u8; 16] = [0; 16];
+            octets.clone_from_slice(&b[..16]);
+            Some(Ipv6Addr::from(octets).to_string())
+        }
+        _ => None,
+    }
+}
+
+// Protocol records are a type byte, a length byte and type-specific data
+fn protocol_records(b: &[u8], count: u8) -> Vec<(u8, &[u8])> {
+    let mut records = Vec::new();
+    let mut pos = 0;
+    for _i in 0..count {
+        if pos + 2 > b.len() {
+            break;
+        }
+        let end = pos + 2 + usize::from(b[pos + 1]);
+        if end > b.len() {
+            warn!("Protocol record {} overruns the structure", records.len());
+            break;
+        }
+        records.push((b[pos], &b[pos + 2..end]));
+        pos = end;
+    }
+    records
+}
+
+fn fmt_redfish_over_ip(f: &mut fmt::Formatter<'_>, data: &[u8]) -> fmt::Result {
+    if data.len() < 0x5b {
+        return writeln!(f, "  Truncated Redfish over IP record. Buggy firmware?");
+    }
+    writeln!(f, "  Service UUID: {}", uuid_string(&data[0..0x10]))?;
+    writeln!(f, "  Host IP Assignment Type: {}", ip_assignment_type(data[0x10]))?;
+    if let Some(ip) = ip_string(data[0x11], &data[0x12..0x22]) {
+        writeln!(f, "  Host IP Address: {}", ip)?;
+        if let Some(mask) = ip_string(data[0x11], &data[0x22..0x32]) {
+            writeln!(f, "  Host IP Mask: {}", mask)?;
+        }
+    }
+    writeln!(f, "  Redfish Service IP Discovery Type: {}", ip_assignment_type(data[0x32]))?;
+    let service_ip = ip_string(data[0x33], &data[0x34..0x44]);
+    if let Some(ip) = &service_ip {
+        writeln!(f, "  Redfish Service IP Address: {}", ip)?;
+        if let Some(mask) = ip_string(data[0x33], &data[0x44..0x54]) {
+            writeln!(f, "  Redfish Service IP Mask: {}", mask)?;
+        }
+    }
+    let port = word_at(data, 0x54);
+    writeln!(f, "  Redfish Service Port: {}", port)?;
+    let vlan = dword_at(data, 0x56);
+    writeln!(f, "  Redfish Service VLAN: {}", vlan)?;
+    let end = (0x5b + usize::from(data[0x5a])).min(data.len());
+    let hostname = String::from_utf8_lossy(&data[0x5b..end]);
+    let hostname = hostname.trim_end_matches('\0');
+    if !hostname.is_empty() {
+        writeln!(f, "  Redfish Service Hostname: {}", hostname)?;
+    }
+    // IPv6 literals need brackets in a URL
+    match (data[0x33], service_ip) {
+        (0x1, Some(ip)) => writeln!(f, "  Redfish Service URL: https://{}:{}", ip, port),
+        (0x2, Some(ip)) => writeln!(f, "  Redfish Service URL: https://[{}]:{}", ip, port),
+        _ => Ok(()),
+    }
+}
+
+impl Table {
+    fn fmt_device_descriptor(&self, f: &mut fmt::Formatter<'_>, data: &[u8]) -> fmt::Result {
+        if data.is_empty() {
+            return writeln!(f, "Device Type: Missing. Buggy firmware?");
+        }
+        match data[0] {
+            0x02 if data.len() >= 5 => {
+                writeln!(f, "Device Type: USB")?;
+                writeln!(f, "idVendor: 0x{:04x}", word_at(data, 1))?;
+                writeln!(f, "idProduct: 0x{:04x}", word_at(data, 3))?;
+                // The serial number is a USB string descriptor: length,
+                // descriptor type, then UTF-16LE characters. The length
+                // counts its own two header bytes.
+                if data.len() >= 7 && data[5] >= 2 && data[6] == 0x03 {
+                    let end = (5 + usize::from(data[5])).min(data.len());
+                    let chars: Vec<u16> = data[7..end]
+                        .chunks_exact(2)
+                        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                        .collect();
+                    writeln!(f, "Serial Number: {}", String::from_utf16_lossy(&chars))?;
+                }
+                Ok(())
+            }
+            0x03 if data.len() >= 9 => {
+                writeln!(f, "Device Type: PCI/PCIe")?;
+                writeln!(f, "VendorID: 0x{:04x}", word_at(data, 1))?;
+                writeln!(f, "DeviceID: 0x{:04x}", word_at(data, 3))?;
+                writeln!(f, "SubVendorID: 0x{:04x}", word_at(data, 5))?;
+                writeln!(f, "SubDeviceID: 0x{:04x}", word_at(data, 7))
+            }
+            0x04 | 0x05 => self.fmt_v2_descriptor(f, data),
+            0x02 | 0x03 => writeln!(f, "Device Type: Truncated descriptor. Buggy firmware?"),
+            0x80..=0xff => writeln!(f, "Device Type: OEM"),
+            _ => writeln!(f, "Device Type: Unrecognized device type. Probably a bug."),
+        }
+    }
+
+    // The v2 descriptors (DSP0270) start with their own length, which
+    // counts from the length byte. The bus address, characteristics and
+    // credential bootstrapping handle were added in DSP0270 1.3.
+    fn fmt_v2_descriptor(&self, f: &mut fmt::Formatter<'_>, data: &[u8]) -> fmt::Result {
+        let usb = data[0] == 0x04;
+        let min_len = if usb { 0xc } else { 0xf };
+        let d = match data.get(1) {
+            Some(len) => &data[1..(1 + usize::from(*len)).min(data.len())],
+            None => &data[1..],
+        };
+        if d.len() < min_len || usize::from(d[0]) < min_len {
+            return writeln!(f, "Device Type: Truncated descriptor. Buggy firmware?");
+        }
+        let characteristics_offset = if usb {
+            writeln!(f, "Device Type: USB")?;
+            writeln!(f, "idVendor: 0x{:04x}", word_at(d, 1))?;
+            writeln!(f, "idProduct: 0x{:04x}", word_at(d, 3))?;
+            writeln!(f, "Serial Number: {}", self.string_value(d[5].into()))?;
+            writeln!(f, "MAC Address: {}", mac_string(&d[6..0xc]))?;
+            0xc
+        } else {
+            writeln!(f, "Device Type: PCI/PCIe")?;
+            writeln!(f, "VendorID: 0x{:04x}", word_at(d, 1))?;
+            writeln!(f, "DeviceID: 0x{:04x}", word_at(d, 3))?;
+            writeln!(f, "SubVendorID: 0x{:04x}", word_at(d, 5))?;
+            writeln!(f, "SubDeviceID: 0x{:04x}", word_at(d, 7))?;
+            writeln!(f, "MAC Address: {}", mac_string(&d[9..0xf]))?;
+            if d.len() >= 0x13 {
+                writeln!(
+                    f,
+                    "Bus Address: {:04x}:{:02x}:{:02x}.{:x}",
+                    word_at(d, 0xf),
+                    d[0x11],
+                    d[0x12] >> 3,
+                    d[0x12] & 0x7
+                )?;
+            }
+            0x13
+        };
+        if d.len() >= characteristics_offset + 2 {
+            let bit_strings = [(1, "Credential Bootstrapping via IPMI is supported")];
+            writeln!(f, "Device Characteristics:")?;
+            decode_byte(f, d[characteristics_offset], &bit_strings)?;
+        }
+        if d.len() >= characteristics_offset + 4 {
+            writeln!(
+                f,
+                "Credential Bootstrapping Handle: 0x{:04x}",
+                word_at(d, characteristics_offset + 2)
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn fmt_table42(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 42 (Management Controller Host Interface)")?;
+        if self.size() < 0x6 {
+            return Ok(());
+        }
+        let bits = &self.data.bits[..usize::from(self.size()).min(self.data.bits.len())];
+        let kind = bits[0x4];
+        writeln!(f, "Host Interface Type: {}", interface_type(kind))?;
+        let data_end = 0x6 + usize::from(bits[0x5]);
+        if data_end > bits.len() {
+            return writeln!(f, "Interface data overruns the structure. Buggy firmware?");
+        }
+        if kind == NETWORK_HOST_INTERFACE {
+            self.fmt_device_descriptor(f, &bits[0x6..data_end])?;
+        }
+        // Protocol records were added in SMBIOS 3.2
+        if data_end >= bits.len() {
+            return Ok(());
+        }
+        let records = protocol_records(&bits[data_end + 1..], bits[data_end]);
+        for (i, (kind, data)) in records.iter().enumerate() {
+            writeln!(f, "Protocol ID {}: {}", i + 1, protocol_type(*kind))?;
+            if *kind == REDFISH_OVER_IP {
+                fmt_redfish_over_ip(f, data)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::table;
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    #[test]
+    fn test_decode_table42_redfish() {
+        let mut bits = vec![
+            42,   // type
+            0,    // length, filled in below
+            0x0,  // handle (2 bytes)
+            0x2a,
+            0x40, // network host interface
+            9,    // interface data length
+            0x03, // PCI/PCIe device
+            0x86, 0x80, // vendor
+            0x3c, 0x15, // device
+            0x86, 0x80, // subsystem vendor
+            0x01, 0x00, // subsystem device
+            1,    // protocol records
+            0x04, // Redfish over IP
+            0x5e, // protocol data length
+        ];
+        // Service UUID
+        bits.extend_from_slice(&[
+            0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee, 0xff,
+        ]);
+        bits.extend_from_slice(&[0x1, 0x1]); // static, IPv4
+        bits.extend_from_slice(&[169, 254, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        bits.extend_from_slice(&[255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        bits.extend_from_slice(&[0x1, 0x1]); // static, IPv4
+        bits.extend_from_slice(&[169, 254, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        bits.extend_from_slice(&[255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        bits.extend_from_slice(&[0xbb, 0x01]); // port 443
+        bits.extend_from_slice(&[0, 0, 0, 0]); // VLAN
+        bits.push(3);
+        bits.extend_from_slice(b"bmc");
+        bits[1] = bits.len() as u8;
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits,
+            strings: [].to_vec(),
+        };
+        let table = Table {
+            id: TableId::HostInterface,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Host Interface Type: Network"));
+        assert!(r.contains("Device Type: PCI/PCIe\nVendorID: 0x8086\nDeviceID: 0x153c"));
+        assert!(r.contains("Protocol ID 1: Redfish over IP"));
+        assert!(r.contains("  Service UUID: 00112233-4455-6677-8899-aabbccddeeff"));
+        assert!(r.contains("  Host IP Address: 169.254.0.2\n  Host IP Mask: 255.255.0.0"));
+        assert!(r.contains("  Redfish Service IP Address: 169.254.0.1"));
+        assert!(r.contains("  Redfish Service Port: 443"));
+        assert!(r.contains("  Redfish Service Hostname: bmc"));
+        assert!(r.contains("  Redfish Service URL: https://169.254.0.1:443"));
+    }
+
+    #[test]
+    fn test_decode_table42_usb_truncated() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                42,   // type
+                0x12, // length
+                0x0,  // handle (2 bytes)
+                0x2a,
+                0x40, // network host interface
+                0xb,  // interface data length
+                0x02, // USB device
+                0x6b, 0x1d, // idVendor
+                0x03, 0x01, // idProduct
+                6,    // serial number length
+                0x03, // string descriptor
+                b'A', 0, b'B', 0,
+                1,    // protocol records
+                0x04, // Redfish over IP, but no length byte
+            ]
+            .to_vec(),
+            strings: [].to_vec(),
+        };
+        let table = Table {
+            id: TableId::HostInterface,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Device Type: USB\nidVendor: 0x1d6b\nidProduct: 0x0103"));
+        assert!(r.contains("Serial Number: AB"));
+        assert!(!r.contains("Protocol ID"));
+    }
+
+    #[test]
+    fn test_decode_table42_usb_short_serial() {
+        for len in [0, 1].iter() {
+            let table = network_interface(
+                &[
+                    0x02, // USB device
+                    0x6b, 0x1d, // idVendor
+                    0x03, 0x01, // idProduct
+                    *len, // serial number length, too short for its header
+                    0x03, // string descriptor
+                    b'A', 0,
+                ],
+                vec![],
+            );
+            let r = format!("{}", table);
+            println!("{}", r);
+            assert!(r.contains("Device Type: USB\nidVendor: 0x1d6b\nidProduct: 0x0103"));
+            assert!(!r.contains("Serial Number"));
+        }
+    }
+
+    fn network_interface(descriptor: &[u8], strings: Vec<String>) -> Table {
+        let mut bits = vec![
+            42,   // type
+            0,    // length, filled in below
+            0x0,  // handle (2 bytes)
+            0x2a,
+            0x40, // network host interface
+            descriptor.len() as u8,
+        ];
+        bits.extend_from_slice(descriptor);
+        bits.push(0); // no protocol records
+        bits[1] = bits.len() as u8;
+        table(TableId::HostInterface, bits, strings)
+    }
+
+    #[test]
+    fn test_decode_table42_pci_v2() {
+        // DSP0270 1.3 descriptor, exactly 0x17 bytes from the length byte
+        let descriptor = [
+            0x05, // PCI/PCIe v2
+            0x17, // length
+            0x86, 0x80, // vendor
+            0x3c, 0x15, // device
+            0x86, 0x80, // subsystem vendor
+            0x01, 0x00, // subsystem device
+            0x02, 0x00, 0x5e, 0x10, 0x20, 0x30, // MAC address
+            0x00, 0x00, // segment
+            0x3b, // bus
+            0x0a, // device 1, function 2
+            0x01, 0x00, // characteristics
+            0x10, 0x00, // credential bootstrapping handle
+        ];
+        let r = format!("{}", network_interface(&descriptor, [].to_vec()));
+        println!("{}", r);
+        assert!(r.contains("Device Type: PCI/PCIe\nVendorID: 0x8086\nDeviceID: 0x153c"));
+        assert!(r.contains("SubDeviceID: 0x0001\nMAC Address: 02:00:5e:10:20:30"));
+        assert!(r.contains("Bus Address: 0000:3b:01.2"));
+        assert!(r.contains(
+            "Device Characteristics:\n  + Credential Bootstrapping via IPMI is supported"
+        ));
+        assert!(r.contains("Credential Bootstrapping Handle: 0x0010"));
+
+        // DSP0270 1.2 descriptors stop after the MAC address
+        let mut short = descriptor[..0x10].to_vec();
+        short[1] = 0xf;
+        let r = format!("{}", network_interface(&short, [].to_vec()));
+        println!("{}", r);
+        assert!(r.contains("MAC Address: 02:00:5e:10:20:30"));
+        assert!(!r.contains("Bus Address"));
+        assert!(!r.contains("Credential Bootstrapping"));
+    }
+
+    #[test]
+    fn test_decode_table42_usb_v2() {
+        let descriptor = [
+            0x04, // USB v2
+            0x0c, // length
+            0x6b, 0x1d, // idVendor
+            0x03, 0x01, // idProduct
+            1,    // serial number string
+            0x02, 0x00, 0x5e, 0x10, 0x20, 0x31, // MAC address
+        ];
+        let r = format!("{}", network_interface(&descriptor, [String::from("BMC0001")].to_vec()));
+        println!("{}", r);
+        assert!(r.contains("Device Type: USB\nidVendor: 0x1d6b\nidProduct: 0x0103"));
+        assert!(r.contains("Serial Number: BMC0001\nMAC Address: 02:00:5e:10:20:31"));
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::decode_byte;
+use crate::dmi::table::Table;
+use std::fmt;
+
+impl Table {
+    // The vendor ID is up to four ASCII characters, NUL padded
+    pub fn tpm_vendor_id(&self) -> String {
+        self.data.bits[0x4..0x8]
+            .iter()
+            .take_while(|b| **b != 0)
+            .map(|b| if b.is_ascii_graphic() { *b as char } else { '.' })
+            .collect()
+    }
+
+    // The firmware version fields are laid out differently for each
+    // TPM family
+    pub fn tpm_firmware_version(&self) -> Option<String> {
+        match self.data.bits[0x8] {
+            // TPM_VERSION structure: major, minor, revMajor, revMinor
+            0x1 => Some(format!("{}.{}", self.data.bits[0xc], self.data.bits[0xd])),
+            // Major version in the high word, minor in the low word
+            0x2 => {
+                let v = self.dword(0xa);
+                Some(format!("{}.{}", v >> 16, v & 0xffff))
+            }
+            _ => None,
+        }
+    }
+
+    fn fmt_tpm_characteristics(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = self.qword(0x13);
+        writeln!(f, "Characteristics:")?;
+        if c & (1 << 2) != 0 {
+            return writeln!(f, "  + TPM Device characteristics not supported");
+        }
+        let bit_strings = [
+            (1 << 3, "Family configurable via firmware update"),
+            (1 << 4, "Family configurable via platform software support"),
+            (1 << 5, "Family configurable via OEM proprietary mechanism"),
+        ];
+        decode_byte(f, (c & 0xff) as u8, &bit_strings)
+    }
+
+    pub fn fmt_table43(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 43 (TPM Device)")?;
+        if self.size() < 0x1b {
+            return Ok(());
+        }
+        writeln!(f, "Vendor ID: {}", self.tpm_vendor_id())?;
+        let (major, minor) = (self.data.bits[0x8], self.data.bits[0x9]);
+        writeln!(f, "Specification Version: {}.{}", major, minor)?;
+        match self.tpm_firmware_version() {
+            Some(v) => writeln!(f, "Firmware Revision: {}", v)?,
+            None => writeln!(
+                f,
+                "Firmware Revision: Unrecognized TPM family {}. Probably a bug.",
+                major
+            )?,
+        }
+        if major == 0x2 {
+            // Vendor-defined, but some vendors put a build number here
+            writeln!(f, "Firmware Version 2: 0x{:08x}", self.dword(0xe))?;
+        }
+        self.fmt_str(f, 0x12, "Description")?;
+        self.fmt_tpm_characteristics(f)?;
+        if self.size() >= 0x1f {
+            writeln!(f, "OEM-specific Information: 0x{:08x}", self.dword(0x1b))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn tpm(version: [u8; 2], firmware: [u8; 8]) -> Table {
+        let mut bits = vec![
+            43,   // type
+            0x1f, // length
+            0x0,  // handle (2 bytes)
+            0x2b,
+            b'I', b'F', b'X', 0, // vendor ID
+            version[0],
+            version[1],
+        ];
+        bits.extend_from_slice(&firmware);
+        bits.push(1); // description
+        bits.extend_from_slice(&[0x10, 0, 0, 0, 0, 0, 0, 0]); // characteristics
+        bits.extend_from_slice(&[0x78, 0x56, 0x34, 0x12]); // OEM-defined
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits,
+            strings: [String::from("TPM 2.0, Manufacturer: IFX")].to_vec(),
+        };
+        Table {
+            id: TableId::TpmDevice,
+            data: d,
+        }
+    }
+
+    #[test]
+    fn test_decode_table43_tpm2() {
+        let r = format!("{}", tpm([2, 0], [0x12, 0, 0x7, 0, 0x0c, 0x01, 0, 0]));
+        println!("{}", r);
+        assert!(r.contains("Vendor ID: IFX"));
+        assert!(r.contains("Specification Version: 2.0"));
+        assert!(r.contains("Firmware Revision: 7.18"));
+        assert!(r.contains("Firmware Version 2: 0x0000010c"));
+        assert!(r.contains("Description: TPM 2.0, Manufacturer: IFX"));
+        assert!(r.contains("  + Family configurable via platform software support"));
+        assert!(r.contains("OEM-specific Information: 0x12345678"));
+    }
+
+    #[test]
+    fn test_decode_table43_tpm12() {
+        let r = format!("{}", tpm([1, 2], [1, 2, 4, 40, 0, 0, 0, 0]));
+        println!("{}", r);
+        assert!(r.contains("Specification Version: 1.2"));
+        assert!(r.contains("Firmware Revision: 4.40"));
+        assert!(!r.contains("Firmware Version 2"));
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::find_handle;
+use crate::dmi::table::Table;
+use std::fmt;
+
+// Length of the RISC-V processor-specific data, revision 1.0
+const RISCV_BLOCK_LEN: usize = 0x6e;
+
+fn processor_architecture(b: u8) -> &'static str {
+    match b {
+        0x1 => "IA32 (x86)",
+        0x2 => "x64 (x86-64, Intel64, AMD64, EM64T)",
+        0x3 => "Intel Itanium architecture",
+        0x4 => "32-bit ARM (Aarch32)",
+        0x5 => "64-bit ARM (Aarch64)",
+        0x6 => "32-bit RISC-V (RV32)",
+        0x7 => "64-bit RISC-V (RV64)",
+        0x8 => "128-bit RISC-V (RV128)",
+        0x9 => "32-bit LoongArch (LoongArch32)",
+        0xa => "64-bit LoongArch (LoongArch64)",
+        _ => "Unrecognized architecture. Probably a bug.",
+    }
+}
+
+fn riscv_xlen(b: u8) -> &'static str {
+    match b {
+        0x0 => "Unsupported",
+        0x1 => "32-bit",
+        0x2 => "64-bit",
+        0x3 => "128-bit",
+        _ => "Unrecognized register width. Probably a bug.",
+    }
+}
+
+fn le_u128(b: &[u8]) -> u128 {
+    let mut bytes: [u8; 16] = [0; 16];
+    bytes.clone_from_slice(&b[..16]);
+    u128::from_le_bytes(bytes)
+}
+
+fn fmt_riscv_block(f: &mut fmt::Formatter<'_>, data: &[u8], prefix: &str) -> fmt::Result {
+    if data.len() < RISCV_BLOCK_LEN {
+        return writeln!(f, "{}Truncated RISC-V block. Buggy firmware?", prefix);
+    }
+    writeln!(f, "{}Revision: {}.{}", prefix, data[0x1], data[0x0])?;
+    writeln!(f, "{}Hart ID: 0x{:x}", prefix, le_u128(&data[0x3..]))?;
+    let boot = if data[0x13] & 1 != 0 { "Yes" } else { "No" };
+    writeln!(f, "{}Boot Hart: {}", prefix, boot)?;
+    writeln!(f, "{}Machine Vendor ID: 0x{:x}", prefix, le_u128(&data[0x14..]))?;
+    writeln!(f, "{}Machine Architecture ID: 0x{:x}", prefix, le_u128(&data[0x24..]))?;
+    writeln!(f, "{}Machine Implementation ID: 0x{:x}", prefix, le_u128(&data[0x34..]))?;
+    // Bit n of the misa-style mask is extension letter 'A' + n
+    let isa = u32::from_le_bytes([data[0x44], data[0x45], data[0x46], data[0x47]]);
+    let extensions: String = (0..26u8)
+        .filter(|n| isa & (1 << n) != 0)
+        .map(|n| (b'A' + n) as char)
+        .collect();
+    writeln!(f, "{}Instruction Set Supported: {}", prefix, extensions)?;
+    let levels: Vec<&str> = [(0, "Machine"), (2, "Supervisor"), (3, "User")]
+        .iter()
+        .filter(|(bit, _)| data[0x48] & (1 << bit) != 0)
+        .map(|(_, name)| *name)
+        .collect();
+    writeln!(f, "{}Privilege Levels Supported: {}", prefix, levels.join(", "))?;
+    writeln!(f, "{}Machine Exception Trap Delegation: 0x{:x}", prefix, le_u128(&data[0x49..]))?;
+    writeln!(f, "{}Machine Interrupt Trap Delegation: 0x{:x}", prefix, le_u128(&data[0x59..]))?;
+    writeln!(f, "{}Register Width: {}", prefix, riscv_xlen(data[0x69]))?;
+    writeln!(f, "{}Machine Mode XLEN: {}", prefix, riscv_xlen(data[0x6a]))?;
+    writeln!(f, "{}Supervisor Mode XLEN: {}", prefix, riscv_xlen(data[0x6c]))?;
+    writeln!(f, "{}User Mode XLEN: {}", prefix, riscv_xlen(data[0x6d]))
+}
+
+impl Table {
+    pub fn fmt_processor_block(&self, f: &mut fmt::Formatter<'_>, prefix: &str) -> fmt::Result {
+        let end = usize::from(self.size()).min(self.data.bits.len());
+        let data_end = (0x8 + usize::from(self.data.bits[0x6])).min(end);
+        let data = &self.data.bits[0x8..data_end];
+        let arch = self.data.bits[0x7];
+        writeln!(f, "{}Processor Architecture: {}", prefix, processor_architecture(arch))?;
+        match arch {
+            0x6..=0x8 => fmt_riscv_block(f, data, prefix),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn fmt_table44(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 44 (Processor Additional Information)")?;
+        if self.size() < 0x8 {
+            return Ok(());
+        }
+        writeln!(f, "Referenced Handle: 0x{:04x}", self.word(0x4))?;
+        self.fmt_processor_block(f, "")
+    }
+
+    pub fn fmt_table44_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        if self.size() < 0x8 {
+            return Ok(());
+        }
+        match find_handle(tables, self.word(0x4)) {
+            Some(t) => writeln!(f, "Processor: {}", t.summary()),
+            None => writeln!(f, "Processor: Handle 0x{:04x} not found", self.word(0x4)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn riscv_info() -> Table {
+        let mut bits = vec![
+            44,   // type
+            0x76, // length
+            0x0,  // handle (2 bytes)
+            0x2c,
+            0x0,  // referenced handle (2 bytes)
+            0x4,
+            0x6e, // block length
+            0x7,  // RV64
+            0x0,  // revision (2 bytes)
+            0x1,
+            0x6e, // structure length
+        ];
+        let mut hart = [0; 16];
+        hart[0] = 0x2;
+        bits.extend_from_slice(&hart); // hart ID
+        bits.push(1); // boot hart
+        let mut vendor = [0; 16];
+        vendor[0] = 0x89;
+        vendor[1] = 0x04;
+        bits.extend_from_slice(&vendor); // mvendorid
+        bits.extend_from_slice(&[0; 16]); // marchid
+        bits.extend_from_slice(&[0; 16]); // mimpid
+        bits.extend_from_slice(&[0x2d, 0x11, 0x14, 0x0]); // IMAFDC, S, U
+        bits.push(0xd); // M, S, U
+        bits.extend_from_slice(&[0; 32]); // trap delegation
+        bits.extend_from_slice(&[0x2, 0x2, 0x0, 0x2, 0x2]); // XLEN
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits,
+            strings: [].to_vec(),
+        };
+        Table {
+            id: TableId::ProcessorAdditional,
+            data: d,
+        }
+    }
+
+    #[test]
+    fn test_decode_table44_riscv() {
+        let r = format!("{}", riscv_info());
+        println!("{}", r);
+        assert!(r.contains("Referenced Handle: 0x0400"));
+        assert!(r.contains("Processor Architecture: 64-bit RISC-V (RV64)"));
+        assert!(r.contains("Revision: 1.0"));
+        assert!(r.contains("Hart ID: 0x2\nBoot Hart: Yes"));
+        assert!(r.contains("Machine Vendor ID: 0x489"));
+        assert!(r.contains("Instruction Set Supported: ACDFIMSU"));
+        assert!(r.contains("Privilege Levels Supported: Machine, Supervisor, User"));
+        assert!(r.contains("Machine Mode XLEN: 64-bit\nSupervisor Mode XLEN: 64-bit"));
+    }
+
+    #[test]
+    fn test_table44_attached_to_processor() {
+        let mut bits = vec![4, 0x1a, 0x0, 0x4, 1, 0x3];
+        bits.resize(0x1a, 0);
+        let cpu = Table {
+            id: TableId::Processor,
+            data: Data {
+                location: 0,
+                string_location: 0,
+                next_loc: 0,
+                bits,
+                strings: [String::from("CPU0")].to_vec(),
+            },
+        };
+        let tables = [cpu, riscv_info()];
+        let r = format!("{}", tables[0].linked(&tables));
+        println!("{}", r);
+        assert!(r.contains("Additional Information: Handle 0x2c00, Type 44"));
+        assert!(r.contains("  Processor Architecture: 64-bit RISC-V (RV64)"));
+        assert!(r.contains("  Boot Hart: Yes"));
+
+        let r = format!("{}", tables[1].linked(&tables));
+        assert!(r.contains("Processor: Handle 0x0400, Type 4 (Processor Information): CPU0"));
+    }
+}
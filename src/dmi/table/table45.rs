@@ -0,0 +1,220 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::decode_byte;
+use crate::dmi::table::find_handle;
+use crate::dmi::table::Table;
+use std::fmt;
+
+fn version_format(b: u8) -> &'static str {
+    match b {
+        0x0 => "Free-form",
+        0x1 => "MAJOR.MINOR",
+        0x2 => "32-bit hexadecimal",
+        0x3 => "64-bit hexadecimal",
+        0x80..=0xff => "OEM-specific",
+        _ => "Unrecognized version format. Probably a bug.",
+    }
+}
+
+fn id_format(b: u8) -> &'static str {
+    match b {
+        0x0 => "Free-form",
+        0x1 => "UEFI GUID",
+        0x80..=0xff => "OEM-specific",
+        _ => "Unrecognized ID format. Probably a bug.",
+    }
+}
+
+fn firmware_state(b: u8) -> &'static str {
+    match b {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "Disabled",
+        0x4 => "Enabled",
+        0x5 => "Absent",
+        0x6 => "Standby Offline",
+        0x7 => "Standby Spare",
+        0x8 => "Unavailable Offline",
+        _ => "Unrecognized state. Probably a bug.",
+    }
+}
+
+impl Table {
+    fn firmware_string(&self, offset: usize) -> &str {
+        self.string_value(self.data.bits[offset].into())
+    }
+
+    pub fn associated_components(&self) -> Vec<u16> {
+        if self.size() < 0x18 {
+            return Vec::new();
+        }
+        self.records(0x18, self.data.bits[0x17].into(), 2)
+            .iter()
+            .map(|r| u16::from_le_bytes([r[0], r[1]]))
+            .collect()
+    }
+
+    // One line summary for `dmitool firmware`
+    pub fn firmware_summary(&self) -> Option<String> {
+        if self.id() != 45 || self.size() < 0x17 {
+            return None;
+        }
+        Some(format!(
+            "{}: {} ({}, {})",
+            self.firmware_string(0x4),
+            self.firmware_string(0x5),
+            self.firmware_string(0xa),
+            firmware_state(self.data.bits[0x16])
+        ))
+    }
+
+    pub fn fmt_table45(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 45 (Firmware Inventory Information)")?;
+        if self.size() < 0x18 {
+            return Ok(());
+        }
+        self.fmt_str(f, 0x4, "Firmware Component Name")?;
+        writeln!(
+            f,
+            "Firmware Version: {} ({})",
+            self.firmware_string(0x5),
+            version_format(self.data.bits[0x6])
+        )?;
+        writeln!(
+            f,
+            "Firmware ID: {} ({})",
+            self.firmware_string(0x7),
+            id_format(self.data.bits[0x8])
+        )?;
+        self.fmt_str(f, 0x9, "Release Date")?;
+        self.fmt_str(f, 0xa, "Manufacturer")?;
+        self.fmt_str(f, 0xb, "Lowest Supported Firmware Version")?;
+        match self.qword(0xc) {
+            u64::MAX => writeln!(f, "Image Size: Unknown")?,
+            size => writeln!(f, "Image Size: {} bytes", size)?,
+        }
+        let bit_strings = [(1, "Updatable"), (1 << 1, "Write-Protect")];
+        writeln!(f, "Characteristics:")?;
+        decode_byte(f, self.data.bits[0x14], &bit_strings)?;
+        writeln!(f, "State: {}", firmware_state(self.data.bits[0x16]))?;
+        writeln!(f, "Associated Components: {}", self.data.bits[0x17])?;
+        for h in self.associated_components() {
+            writeln!(f, "  + Handle 0x{:04x}", h)?;
+        }
+        Ok(())
+    }
+
+    pub fn fmt_table45_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        let handles = self.associated_components();
+        if handles.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "Components:")?;
+        for h in handles {
+            match find_handle(tables, h) {
+                Some(t) => writeln!(f, "  + {}", t.summary())?,
+                None => writeln!(f, "  + Handle 0x{:04x} not found", h)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn firmware() -> Table {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                45,   // type
+                0x1a, // length
+                0x0,  // handle (2 bytes)
+                0x2d,
+                1,    // component name
+                2,    // version
+                0x1,  // MAJOR.MINOR
+                3,    // firmware ID
+                0x1,  // UEFI GUID
+                4,    // release date
+                5,    // manufacturer
+                0,    // lowest supported version
+                0x0, 0x0, 0x0, 0x2, 0x0, 0x0, 0x0, 0x0, // image size
+                0x3, 0x0, // updatable, write-protected
+                0x4,  // enabled
+                1,    // associated components
+                0x0, 0x2, // handle
+            ]
+            .to_vec(),
+            strings: [
+                String::from("BMC Firmware"),
+                String::from("2.14"),
+                String::from("6d7c7a8c-1bb2-4c8f-9e67-d1f9a4c2e0b3"),
+                String::from("2024-03-01"),
+                String::from("ACME"),
+            ]
+            .to_vec(),
+        };
+        Table {
+            id: TableId::FirmwareInventory,
+            data: d,
+        }
+    }
+
+    #[test]
+    fn test_decode_table45() {
+        let r = format!("{}", firmware());
+        println!("{}", r);
+        assert!(r.contains("Firmware Component Name: BMC Firmware"));
+        assert!(r.contains("Firmware Version: 2.14 (MAJOR.MINOR)"));
+        assert!(r.contains("Firmware ID: 6d7c7a8c-1bb2-4c8f-9e67-d1f9a4c2e0b3 (UEFI GUID)"));
+        assert!(r.contains("Lowest Supported Firmware Version: Unspecified"));
+        assert!(r.contains("Image Size: 33554432 bytes"));
+        assert!(r.contains("  + Updatable\n  + Write-Protect"));
+        assert!(r.contains("State: Enabled"));
+        assert!(r.contains("Associated Components: 1\n  + Handle 0x0200"));
+        assert_eq!(
+            firmware().firmware_summary().unwrap(),
+            "BMC Firmware: 2.14 (ACME, Enabled)"
+        );
+    }
+
+    #[test]
+    fn test_table45_components() {
+        let mut bits = vec![2, 0x8, 0x0, 0x2, 1];
+        bits.resize(0x8, 0);
+        let board = Table {
+            id: TableId::Baseboard,
+            data: Data {
+                location: 0,
+                string_location: 0,
+                next_loc: 0,
+                bits,
+                strings: [String::from("ACME")].to_vec(),
+            },
+        };
+        let tables = [board, firmware()];
+        let r = format!("{}", tables[1].linked(&tables));
+        println!("{}", r);
+        assert!(r.contains("Components:\n  + Handle 0x0200, Type 2 (Baseboard Information)"));
+    }
+}
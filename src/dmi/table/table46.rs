@@ -0,0 +1,143 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::find_handle;
+use crate::dmi::table::Table;
+use std::fmt;
+
+fn string_property_id(id: u16) -> String {
+    match id {
+        0x1 => String::from("UEFI Device Path"),
+        0x8000..=0xbfff => format!("BIOS Vendor Specific (0x{:04x})", id),
+        0xc000..=0xffff => format!("OEM Specific (0x{:04x})", id),
+        _ => format!("Unrecognized property ID 0x{:04x}. Probably a bug.", id),
+    }
+}
+
+impl Table {
+    pub fn string_property_parent(&self) -> Option<u16> {
+        if self.id() != 46 || self.size() < 0x9 {
+            return None;
+        }
+        Some(self.word(0x7))
+    }
+
+    pub fn fmt_table46(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 46 (String Property)")?;
+        if self.size() < 0x9 {
+            return Ok(());
+        }
+        writeln!(f, "String Property ID: {}", string_property_id(self.word(0x4)))?;
+        self.fmt_str(f, 0x6, "String Property Value")?;
+        writeln!(f, "Parent Handle: 0x{:04x}", self.word(0x7))
+    }
+
+    pub fn fmt_table46_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        if let Some(h) = self.string_property_parent() {
+            match find_handle(tables, h) {
+                Some(t) => writeln!(f, "Parent: {}", t.summary())?,
+                None => writeln!(f, "Parent: Handle 0x{:04x} not found", h)?,
+            }
+        }
+        Ok(())
+    }
+
+    pub fn fmt_string_properties(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        tables: &[Table],
+    ) -> fmt::Result {
+        let mut header = false;
+        for t in tables.iter() {
+            if t.string_property_parent() != Some(self.handle()) {
+                continue;
+            }
+            if !header {
+                writeln!(f, "String Properties:")?;
+                header = true;
+            }
+            writeln!(
+                f,
+                "  + {}: {}",
+                string_property_id(t.word(0x4)),
+                t.string_value(t.data.bits[0x6].into())
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    fn device_path() -> Table {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                46,  // type
+                0x9, // length
+                0x0, // handle (2 bytes)
+                0x2e,
+                0x1, // UEFI device path (2 bytes)
+                0x0,
+                1,   // value
+                0x0, // parent handle (2 bytes)
+                0x9,
+            ]
+            .to_vec(),
+            strings: [String::from("PciRoot(0x0)/Pci(0x1C,0x0)")].to_vec(),
+        };
+        Table {
+            id: TableId::StringProperty,
+            data: d,
+        }
+    }
+
+    #[test]
+    fn test_decode_table46() {
+        let r = format!("{}", device_path());
+        println!("{}", r);
+        assert!(r.contains("String Property ID: UEFI Device Path"));
+        assert!(r.contains("String Property Value: PciRoot(0x0)/Pci(0x1C,0x0)"));
+        assert!(r.contains("Parent Handle: 0x0900"));
+    }
+
+    #[test]
+    fn test_table46_attached_to_parent() {
+        let slot = Table {
+            id: TableId::Other,
+            data: Data {
+                location: 0,
+                string_location: 0,
+                next_loc: 0,
+                bits: [9, 0x11, 0x0, 0x9, 1].to_vec(),
+                strings: [String::from("PCIE1")].to_vec(),
+            },
+        };
+        let tables = [slot, device_path()];
+        let r = format!("{}", tables[0].linked(&tables));
+        println!("{}", r);
+        assert!(r.contains("String Properties:\n  + UEFI Device Path: PciRoot(0x0)/Pci(0x1C,0x0)"));
+
+        let r = format!("{}", tables[1].linked(&tables));
+        assert!(r.contains("Parent: Handle 0x0900, Type 9 (System Slots): PCIE1"));
+    }
+}
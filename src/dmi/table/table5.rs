@@ -0,0 +1,208 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::decode_byte;
+use crate::dmi::table::find_handle;
+use crate::dmi::table::Table;
+use std::fmt;
+
+fn error_detecting_method(b: u8) -> &'static str {
+    match b {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "None",
+        0x4 => "8-bit Parity",
+        0x5 => "32-bit ECC",
+        0x6 => "64-bit ECC",
+        0x7 => "128-bit ECC",
+        0x8 => "CRC",
+        _ => "Unrecognized error detecting method. Probably a bug.",
+    }
+}
+
+fn interleave(b: u8) -> &'static str {
+    match b {
+        0x1 => "Other",
+        0x2 => "Unknown",
+        0x3 => "One-way Interleave",
+        0x4 => "Two-way Interleave",
+        0x5 => "Four-way Interleave",
+        0x6 => "Eight-way Interleave",
+        0x7 => "Sixteen-way Interleave",
+        _ => "Unrecognized interleave. Probably a bug.",
+    }
+}
+
+impl Table {
+    fn fmt_error_correcting(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        offset: usize,
+        label: &str,
+    ) -> fmt::Result {
+        let bit_strings = [
+            (1, "Other"),
+            (1 << 1, "Unknown"),
+            (1 << 2, "None"),
+            (1 << 3, "Single-bit Error Correcting"),
+            (1 << 4, "Double-bit Error Correcting"),
+            (1 << 5, "Error Scrubbing"),
+        ];
+        writeln!(f, "{}:", label)?;
+        decode_byte(f, self.data.bits[offset], &bit_strings)
+    }
+
+    // Shared with Type 6, which uses the same memory type bits
+    pub fn fmt_memory_types(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        offset: usize,
+        label: &str,
+    ) -> fmt::Result {
+        let low = [
+            (1, "Other"),
+            (1 << 1, "Unknown"),
+            (1 << 2, "Standard"),
+            (1 << 3, "FPM"),
+            (1 << 4, "EDO"),
+            (1 << 5, "Parity"),
+            (1 << 6, "ECC"),
+            (1 << 7, "SIMM"),
+        ];
+        let high = [(1, "DIMM"), (1 << 1, "Burst EDO"), (1 << 2, "SDRAM")];
+        let types = self.word(offset);
+        writeln!(f, "{}:", label)?;
+        decode_byte(f, (types & 0xff) as u8, &low)?;
+        decode_byte(f, (types >> 8) as u8, &high)
+    }
+
+    pub fn memory_module_handles(&self) -> Vec<u16> {
+        if self.size() < 0xf {
+            return Vec::new();
+        }
+        self.records(0xf, self.data.bits[0xe].into(), 2)
+            .iter()
+            .map(|r| u16::from_le_bytes([r[0], r[1]]))
+            .collect()
+    }
+
+    pub fn fmt_table5(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 5 (Memory Controller Information)")?;
+        if self.size() < 0xf {
+            return Ok(());
+        }
+        writeln!(f, "Error Detecting Method: {}", error_detecting_method(self.data.bits[0x4]))?;
+        self.fmt_error_correcting(f, 0x5, "Error Correcting Capabilities")?;
+        writeln!(f, "Supported Interleave: {}", interleave(self.data.bits[0x6]))?;
+        writeln!(f, "Current Interleave: {}", interleave(self.data.bits[0x7]))?;
+        let max = u32::from(self.data.bits[0x8]);
+        if max < 32 {
+            writeln!(f, "Maximum Memory Module Size: {} MB", 1u64 << max)?;
+        } else {
+            writeln!(f, "Maximum Memory Module Size: Out of range. Buggy firmware?")?;
+        }
+        let speeds = [
+            (1, "Other"),
+            (1 << 1, "Unknown"),
+            (1 << 2, "70 ns"),
+            (1 << 3, "60 ns"),
+            (1 << 4, "50 ns"),
+        ];
+        writeln!(f, "Supported Speeds:")?;
+        decode_byte(f, self.data.bits[0x9], &speeds)?;
+        self.fmt_memory_types(f, 0xb, "Supported Memory Types")?;
+        let voltages = [(1, "5.0 V"), (1 << 1, "3.3 V"), (1 << 2, "2.9 V")];
+        writeln!(f, "Memory Module Voltage:")?;
+        decode_byte(f, self.data.bits[0xd], &voltages)?;
+        let handles = self.memory_module_handles();
+        writeln!(f, "Associated Memory Slots: {}", handles.len())?;
+        for h in handles.iter() {
+            writeln!(f, "  + Handle 0x{:04x}", h)?;
+        }
+        // SMBIOS 2.1+
+        let enabled = 0xf + 2 * handles.len();
+        if usize::from(self.size()) > enabled {
+            self.fmt_error_correcting(f, enabled, "Enabled Error Correcting Capabilities")?;
+        }
+        Ok(())
+    }
+
+    pub fn fmt_table5_links(&self, f: &mut fmt::Formatter<'_>, tables: &[Table]) -> fmt::Result {
+        let handles = self.memory_module_handles();
+        if handles.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "Memory Modules:")?;
+        for h in handles {
+            match find_handle(tables, h) {
+                Some(t) => writeln!(f, "  + {}", t.summary())?,
+                None => writeln!(f, "  + Handle 0x{:04x} not found", h)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    #[test]
+    fn test_decode_table5() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                5,    // type
+                0x14, // length
+                0x0,  // handle (2 bytes)
+                0x5,
+                0x6,  // 64-bit ECC
+                0x8,  // single-bit error correcting
+                0x4,  // two-way interleave
+                0x3,  // one-way interleave
+                0x6,  // 64 MB modules
+                0x8, 0x0, // 60 ns
+                0x0, 0x5, // DIMM, SDRAM
+                0x2,  // 3.3 V
+                2,    // associated slots
+                0x0, 0x6, // handle
+                0x1, 0x6, // handle
+                0x4,  // none enabled
+            ]
+            .to_vec(),
+            strings: [].to_vec(),
+        };
+        let table = Table {
+            id: TableId::MemoryController,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Error Detecting Method: 64-bit ECC"));
+        assert!(r.contains("Error Correcting Capabilities:\n  + Single-bit Error Correcting"));
+        assert!(r.contains("Supported Interleave: Two-way Interleave"));
+        assert!(r.contains("Maximum Memory Module Size: 64 MB"));
+        assert!(r.contains("Supported Speeds:\n  + 60 ns"));
+        assert!(r.contains("Supported Memory Types:\n  + DIMM\n  + SDRAM"));
+        assert!(r.contains("Memory Module Voltage:\n  + 3.3 V"));
+        assert!(r.contains("Associated Memory Slots: 2\n  + Handle 0x0600\n  + Handle 0x0601"));
+        assert!(r.contains("Enabled Error Correcting Capabilities:\n  + None"));
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::decode_byte;
+use crate::dmi::table::Table;
+use std::fmt;
+
+fn module_size(b: u8) -> String {
+    let banks = if b & 0x80 != 0 {
+        " (Double-bank Connection)"
+    } else {
+        " (Single-bank Connection)"
+    };
+    match b & 0x7f {
+        0x7d => String::from("Not Determinable"),
+        0x7e => String::from("Disabled"),
+        0x7f => String::from("Not Installed"),
+        n if n < 32 => format!("{} MB{}", 1u64 << n, banks),
+        _ => String::from("Out of range. Buggy firmware?"),
+    }
+}
+
+impl Table {
+    fn fmt_bank_connections(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Each nibble names a RAS line, 0xf means no connection
+        let b = self.data.bits[0x5];
+        let banks: Vec<String> = [b >> 4, b & 0xf]
+            .iter()
+            .filter(|n| **n != 0xf)
+            .map(|n| n.to_string())
+            .collect();
+        if banks.is_empty() {
+            writeln!(f, "Bank Connections: None")
+        } else {
+            writeln!(f, "Bank Connections: {}", banks.join(" "))
+        }
+    }
+
+    pub fn fmt_table6(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 6 (Memory Module Information)")?;
+        if self.size() < 0xc {
+            return Ok(());
+        }
+        self.fmt_str(f, 0x4, "Socket Designation")?;
+        self.fmt_bank_connections(f)?;
+        match self.data.bits[0x6] {
+            0 => writeln!(f, "Current Speed: Unknown")?,
+            ns => writeln!(f, "Current Speed: {} ns", ns)?,
+        }
+        self.fmt_memory_types(f, 0x7, "Type")?;
+        writeln!(f, "Installed Size: {}", module_size(self.data.bits[0x9]))?;
+        writeln!(f, "Enabled Size: {}", module_size(self.data.bits[0xa]))?;
+        let status = self.data.bits[0xb];
+        if status & (1 << 2) != 0 {
+            return writeln!(f, "Error Status: See Event Log");
+        }
+        if status & 0x3 == 0 {
+            return writeln!(f, "Error Status: OK");
+        }
+        let bit_strings = [(1, "Uncorrectable Errors"), (1 << 1, "Correctable Errors")];
+        writeln!(f, "Error Status:")?;
+        decode_byte(f, status, &bit_strings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+
+    #[test]
+    fn test_decode_table6() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                6,    // type
+                0xc,  // length
+                0x0,  // handle (2 bytes)
+                0x6,
+                1,    // socket designation
+                0x01, // banks 0 and 1
+                60,   // current speed
+                0x0, 0x1, // DIMM
+                0x85, // 32 MB, double-bank
+                0x7e, // disabled
+                0x2,  // correctable errors
+            ]
+            .to_vec(),
+            strings: [String::from("DIMM0")].to_vec(),
+        };
+        let table = Table {
+            id: TableId::MemoryModule,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Socket Designation: DIMM0"));
+        assert!(r.contains("Bank Connections: 0 1"));
+        assert!(r.contains("Current Speed: 60 ns"));
+        assert!(r.contains("Type:\n  + DIMM"));
+        assert!(r.contains("Installed Size: 32 MB (Double-bank Connection)"));
+        assert!(r.contains("Enabled Size: Disabled"));
+        assert!(r.contains("Error Status:\n  + Correctable Errors"));
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright Noah Meyerhans <frodo@morgul.net>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; version 2.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+// 02110-1301, USA.
+
+use crate::dmi::table::Table;
+use std::fmt;
+
+// Output line prefix for each field offset, used to show Type 40
+// entries next to the field they refer to
+pub const TABLE8_FIELDS: &[(u8, &str)] = &[
+    (0x4, "Internal Reference Designator:"),
+    (0x5, "Internal Connector Type:"),
+    (0x6, "External Reference Designator:"),
+    (0x7, "External Connector Type:"),
+    (0x8, "Port Type:"),
+];
+
+// Section 7.9.2 of SMBIOS spec 3.5.0
+fn connector_type(b: u8) -> &'static str {
+    match b {
+        0x00 => "None",
+        0x01 => "Centronics",
+        0x02 => "Mini Centronics",
+        0x03 => "Proprietary",
+        0x04 => "DB-25 pin male",
+        0x05 => "DB-25 pin female",
+        0x06 => "DB-15 pin male",
+        0x07 => "DB-15 pin female",
+        0x08 => "DB-9 pin male",
+        0x09 => "DB-9 pin female",
+        0x0a => "RJ-11",
+        0x0b => "RJ-45",
+        0x0c => "50-pin MiniSCSI",
+        0x0d => "Mini-DIN",
+        0x0e => "Micro-DIN",
+        0x0f => "PS/2",
+        0x10 => "Infrared",
+        0x11 => "HP-HIL",
+        0x12 => "Access Bus (USB)",
+        0x13 => "SSA SCSI",
+        0x14 => "Circular DIN-8 male",
+        0x15 => "Circular DIN-8 female",
+        0x16 => "On Board IDE",
+        0x17 => "On Board Floppy",
+        0x18 => "9-pin Dual Inline (pin 10 cut)",
+        0x19 => "25-pin Dual Inline (pin 26 cut)",
+        0x1a => "50-pin Dual Inline",
+        0x1b => "68-pin Dual Inline",
+        0x1c => "On Board Sound Input from CD-ROM",
+        0x1d => "Mini-Centronics Type-14",
+        0x1e => "Mini-Centronics Type-26",
+        0x1f => "Mini-jack (headphones)",
+        0x20 => "BNC",
+        0x21 => "1394",
+        0x22 => "SAS/SATA Plug Receptacle",
+        0x23 => "USB Type-C Receptacle",
+        0xa0 => "PC-98",
+        0xa1 => "PC-98Hireso",
+        0xa2 => "PC-H98",
+        0xa3 => "PC-98Note",
+        0xa4 => "PC-98Full",
+        0xff => "Other",
+        _ => "Unrecognized connector type. Probably a bug.",
+    }
+}
+
+// Section 7.9.3 of SMBIOS spec 3.5.0
+fn port_type(b: u8) -> &'static str {
+    match b {
+        0x00 => "None",
+        0x01 => "Parallel Port XT/AT Compatible",
+        0x02 => "Parallel Port PS/2",
+        0x03 => "Parallel Port ECP",
+        0x04 => "Parallel Port EPP",
+        0x05 => "Parallel Port ECP/EPP",
+        0x06 => "Serial Port XT/AT Compatible",
+        0x07 => "Serial Port 16450 Compatible",
+        0x08 => "Serial Port 16550 Compatible",
+        0x09 => "Serial Port 16550A Compatible",
+        0x0a => "SCSI Port",
+        0x0b => "MIDI Port",
+        0x0c => "Joy Stick Port",
+        0x0d => "Keyboard Port",
+        0x0e => "Mouse Port",
+        0x0f => "SSA SCSI",
+        0x10 => "USB",
+        0x11 => "FireWire (IEEE P1394)",
+        0x12 => "PCMCIA Type I",
+        0x13 => "PCMCIA Type II",
+        0x14 => "PCMCIA Type III",
+        0x15 => "Cardbus",
+        0x16 => "Access Bus Port",
+        0x17 => "SCSI II",
+        0x18 => "SCSI Wide",
+        0x19 => "PC-98",
+        0x1a => "PC-98-Hireso",
+        0x1b => "PC-H98",
+        0x1c => "Video Port",
+        0x1d => "Audio Port",
+        0x1e => "Modem Port",
+        0x1f => "Network Port",
+        0x20 => "SATA",
+        0x21 => "SAS",
+        0x22 => "MFDP (Multi-Function Display Port)",
+        0x23 => "Thunderbolt",
+        0xa0 => "8251 Compatible",
+        0xa1 => "8251 FIFO Compatible",
+        0xff => "Other",
+        _ => "Unrecognized port type. Probably a bug.",
+    }
+}
+
+impl Table {
+    fn fmt_internal_designator(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_str(f, 0x4, "Internal Reference Designator")
+    }
+
+    fn fmt_external_designator(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_str(f, 0x6, "External Reference Designator")
+    }
+
+    pub fn fmt_table8(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Table 8 (Port Connector Information)")?;
+        let len: u8 = self.size();
+        if len < 0x9 {
+            return Ok(());
+        }
+        self.fmt_internal_designator(f)?;
+        writeln!(
+            f,
+            "Internal Connector Type: {}",
+            connector_type(self.data.bits[0x5])
+        )?;
+        self.fmt_external_designator(f)?;
+        writeln!(
+            f,
+            "External Connector Type: {}",
+            connector_type(self.data.bits[0x7])
+        )?;
+        writeln!(f, "Port Type: {}", port_type(self.data.bits[0x8]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmi::table::Data;
+    use crate::dmi::table::Table;
+    use crate::dmi::table::TableId;
+    #[test]
+    fn test_decode_table8() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                8,    // type
+                0x9,  // length
+                0x10, // handle (2 bytes)
+                0x0,
+                1,    // internal reference designator string
+                0x22, // internal connector type (SAS/SATA)
+                0,    // external reference designator string
+                0x0,  // external connector type (none)
+                0x20, // port type (SATA)
+            ]
+            .to_vec(),
+            strings: [String::from("J3A1")].to_vec(),
+        };
+        let table = Table {
+            id: TableId::PortConnector,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("Internal Reference Designator: J3A1"));
+        assert!(r.contains("Internal Connector Type: SAS/SATA Plug Receptacle"));
+        assert!(r.contains("External Reference Designator: Unspecified"));
+        assert!(r.contains("External Connector Type: None"));
+        assert!(r.contains("Port Type: SATA"));
+    }
+
+    #[test]
+    fn test_decode_table8_vendor_types() {
+        let d = Data {
+            location: 0,
+            string_location: 0,
+            next_loc: 0,
+            bits: [
+                8,    // type
+                0x9,  // length
+                0x11, // handle (2 bytes)
+                0x0,
+                0,    // internal reference designator string
+                0x0,  // internal connector type (none)
+                1,    // external reference designator string
+                0xa3, // external connector type (PC-98Note)
+                0xa1, // port type (8251 FIFO)
+            ]
+            .to_vec(),
+            strings: [String::from("COM 2")].to_vec(),
+        };
+        let table = Table {
+            id: TableId::PortConnector,
+            data: d,
+        };
+        let r = format!("{}", table);
+        println!("{}", r);
+        assert!(r.contains("External Reference Designator: COM 2"));
+        assert!(r.contains("External Connector Type: PC-98Note"));
+        assert!(r.contains("Port Type: 8251 FIFO Compatible"));
+    }
+}
@@ -108,22 +108,119 @@ fn do_entrypoint() {
     info!("Found a {} entrypoint!", t.version());
 }
 
-fn do_table(id: u8) {
+fn do_table(id: u8, show_inactive: bool) {
     let entrypoint = match dmi::entrypoint::Entrypoint::read() {
         Ok(t) => t,
         Err(e) => panic!("Unable to read entrypont: {}", e),
     };
-    let t = match dmi::raw::read_raw_table(id, entrypoint) {
+    let tables = match dmi::raw::read_raw_table(id, entrypoint, show_inactive) {
         Ok(t) => t,
         Err(e) => panic!("Unable to read table: {}", e),
     };
-    debug!(
-        "Got a table with ID 0x{:02x} and handle 0x{:04x}",
-        t.id(),
-        t.handle()
-    );
-    for s in t.strings().iter() {
-        debug!("Table has string [{}]", s);
+    for t in tables.iter() {
+        debug!(
+            "Got a table with ID 0x{:02x} and handle 0x{:04x}",
+            t.id(),
+            t.handle()
+        );
+        for s in t.strings().iter() {
+            debug!("Table has string [{}]", s);
+        }
+    }
+}
+
+fn read_all_tables() -> Vec<Table> {
+    let entrypoint = match dmi::entrypoint::Entrypoint::read() {
+        Ok(t) => t,
+        Err(e) => panic!("Unable to read entrypont: {}", e),
+    };
+    match dmi::raw::read_all_tables(&entrypoint, false) {
+        Ok(t) => t,
+        Err(e) => panic!("Unable to read tables: {}", e),
+    }
+}
+
+fn do_oem_string(n: usize) {
+    let tables = read_all_tables();
+    for t in tables.iter().filter(|t| t.id() == 11) {
+        if let Some(s) = t.oem_string(n) {
+            println!("{}", s);
+            return;
+        }
+    }
+    error!("No OEM string {} found", n);
+    std::process::exit(1);
+}
+
+fn do_oem_prefix(prefix: &str) {
+    let tables = read_all_tables();
+    let mut found = false;
+    for t in tables.iter().filter(|t| t.id() == 11) {
+        for (_, s) in t.oem_strings_with_prefix(prefix) {
+            println!("{}", s);
+            found = true;
+        }
+    }
+    if !found {
+        error!("No OEM strings start with {}", prefix);
+        std::process::exit(1);
+    }
+}
+
+fn do_string(keyword: &str) {
+    let tables = read_all_tables();
+    let value = match keyword {
+        "boot-status" => tables.iter().find_map(|t| t.boot_status()),
+        _ => None,
+    };
+    match value {
+        Some(v) => println!("{}", v),
+        None => {
+            error!("No value found for {}", keyword);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn do_firmware() {
+    let tables = read_all_tables();
+    let summaries: Vec<String> = tables.iter().filter_map(|t| t.firmware_summary()).collect();
+    if summaries.is_empty() {
+        error!("No Firmware Inventory (type 45) structures found");
+        std::process::exit(1);
+    }
+    for s in summaries.iter() {
+        println!("{}", s);
+    }
+}
+
+fn parse_offset(s: &str) -> Option<u64> {
+    match s.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => s.parse().ok(),
+    }
+}
+
+fn do_event_log(path: &str, offset: Option<u64>) {
+    let tables = read_all_tables();
+    let t = match tables.iter().find(|t| t.id() == 15) {
+        Some(t) => t,
+        None => {
+            error!("No System Event Log (type 15) structure found");
+            std::process::exit(1);
+        }
+    };
+    let offset = match offset.or_else(|| t.log_area_address()) {
+        Some(o) => o,
+        None => {
+            error!("Event log is not memory-mapped; use --event-log-offset to locate it");
+            std::process::exit(1);
+        }
+    };
+    debug!("Reading event log from {} at 0x{:x}", path, offset);
+    match dmi::eventlog::EventLog::read(&PathBuf::from(path), offset, t) {
+        Ok(log) => print!("{}", log),
+        Err(e) => panic!("Unable to read event log: {}", e),
     }
 }
 
@@ -132,6 +229,7 @@ fn main() {
         .version("0.1.0")
         .author("Noah Meyerhans <frodo@morgul.net>")
         .about("Decodes and prints system information from the SMBIOS")
+        .args_conflicts_with_subcommands(true)
         .arg(
             Arg::new("zero")
                 .short('0')
@@ -147,6 +245,13 @@ fn main() {
                 .conflicts_with("zero")
                 .help("print the given table via the /sys/firmware/dmi/tables"),
         )
+        .arg(
+            Arg::new("show-inactive")
+                .long("show-inactive")
+                .num_args(0)
+                .requires("table")
+                .help("include inactive (type 126) structures"),
+        )
         .arg(
             Arg::new("entrypoint")
                 .short('e')
@@ -155,6 +260,59 @@ fn main() {
                 .conflicts_with("zero")
                 .help("read SMBIOS entrypoint"),
         )
+        .arg(
+            Arg::new("oem-string")
+                .long("oem-string")
+                .num_args(1)
+                .value_name("N")
+                .conflicts_with_all(["zero", "table", "entrypoint"])
+                .help("print OEM string N from the SMBIOS OEM Strings (type 11) table"),
+        )
+        .arg(
+            Arg::new("oem-prefix")
+                .long("oem-prefix")
+                .num_args(1)
+                .value_name("PREFIX")
+                .conflicts_with_all(["zero", "table", "entrypoint", "oem-string"])
+                .help("print all OEM strings starting with PREFIX"),
+        )
+        .arg(
+            Arg::new("string")
+                .short('s')
+                .long("string")
+                .num_args(1)
+                .value_name("KEYWORD")
+                .value_parser(["boot-status"])
+                .conflicts_with_all(["zero", "table", "entrypoint", "oem-string", "oem-prefix"])
+                .help("print the value of the given keyword"),
+        )
+        .arg(
+            Arg::new("event-log")
+                .long("event-log")
+                .num_args(1)
+                .value_name("FILE")
+                .conflicts_with_all([
+                    "zero",
+                    "table",
+                    "entrypoint",
+                    "oem-string",
+                    "oem-prefix",
+                    "string",
+                ])
+                .help("parse the system event log from a memory image or file"),
+        )
+        .arg(
+            Arg::new("event-log-offset")
+                .long("event-log-offset")
+                .num_args(1)
+                .value_name("OFFSET")
+                .requires("event-log")
+                .help("offset of the event log area in FILE (default: its physical address)"),
+        )
+        .subcommand(
+            Command::new("firmware")
+                .about("list firmware components from the Firmware Inventory (type 45) tables"),
+        )
         .arg(
 	    Arg::new("debug")
 		.short('d')
@@ -174,7 +332,9 @@ fn main() {
 
     env_logger::init_from_env(env);
 
-    if args.get_flag("zero") {
+    if args.subcommand_matches("firmware").is_some() {
+        do_firmware();
+    } else if args.get_flag("zero") {
         info!("Getting table zero");
         let table = "0-0";
         let res = read_table(table);
@@ -189,7 +349,23 @@ fn main() {
             Ok(t) => t,
             Err(_e) => panic!("unable to parse table ID"),
         };
-        do_table(table_id);
+        do_table(table_id, args.get_flag("show-inactive"));
+    } else if args.contains_id("oem-string") {
+        let n: usize = match args.get_one::<String>("oem-string").unwrap().parse() {
+            Ok(n) => n,
+            Err(_e) => panic!("unable to parse OEM string number"),
+        };
+        do_oem_string(n);
+    } else if args.contains_id("oem-prefix") {
+        do_oem_prefix(args.get_one::<String>("oem-prefix").unwrap());
+    } else if args.contains_id("string") {
+        do_string(args.get_one::<String>("string").unwrap());
+    } else if args.contains_id("event-log") {
+        let offset = args.get_one::<String>("event-log-offset").map(|o| match parse_offset(o) {
+            Some(o) => o,
+            None => panic!("unable to parse event log offset"),
+        });
+        do_event_log(args.get_one::<String>("event-log").unwrap(), offset);
     } else {
         print_vendor_data();
         print_product_data();